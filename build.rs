@@ -14,7 +14,7 @@ fn main() {
 }
 
 fn extract_firmware(out_dir: &path::PathBuf) {
-    let path = path::PathBuf::from("kernel@3.3.1.zip");
+    let path = path::PathBuf::from("kernel@3.8.0.zip");
     zip_extract(&path, &out_dir).unwrap();
 
     println!("cargo:rustc-link-search={}", out_dir.display());
@@ -65,12 +65,37 @@ fn generate_bindings(out_dir: &path::PathBuf) {
     let bindings = bindgen::Builder::default()
         .header(out_dir.join("include/api.h").to_str().unwrap())
         .whitelist_var(".*_DEFAULT")
+        .whitelist_var("COMPETITION_.*")
+        .whitelist_var("INTERNAL_ADI_PORT")
+        .whitelist_var("LCD_BTN_.*")
+        .whitelist_var("LINK_.*")
+        .whitelist_var("VISION_.*")
+        .whitelist_function("competition_.*")
+        .whitelist_function("controller_.*")
         .whitelist_function("motor_.*")
+        .whitelist_function("optical_.*")
         .whitelist_function("task_.*")
         .whitelist_function("mutex_.*")
+        .whitelist_function("distance_.*")
+        .whitelist_function("ext_adi_.*")
+        .whitelist_function("gps_.*")
+        .whitelist_function("imu_.*")
+        .whitelist_function("lcd_.*")
+        .whitelist_function("link_.*")
         .whitelist_function("millis")
+        .whitelist_function("rotation_.*")
+        .whitelist_function("serial_.*")
+        .whitelist_function("vision_.*")
+        .whitelist_type("adi_.*")
+        .whitelist_type("controller_.*")
+        .whitelist_type("ext_adi_.*")
+        .whitelist_type("gps_.*")
+        .whitelist_type("imu_.*")
+        .whitelist_type("link_.*")
         .whitelist_type("motor_.*")
+        .whitelist_type("optical_.*")
         .whitelist_type("task_.*")
+        .whitelist_type("vision_.*")
         .whitelist_type("mutex_.*")
         .rustified_enum("motor_.*")
         .rustified_enum("task_.*")
@@ -8,18 +8,22 @@ use vex_rt::rtos::Task;
 struct DelayBot;
 
 impl Robot for DelayBot {
-    fn initialize() -> Self {
-        Self
+    fn initialize() -> Result<Self, Error> {
+        Ok(Self)
     }
-    fn autonomous(&self, _: Context) {}
-    fn opcontrol(&self, _: Context) {
+    fn autonomous(&self, _: Context) -> Result<(), Error> {
+        Ok(())
+    }
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
         let x: u32 = 0;
         loop {
             println!("x = {}", x);
             Task::delay(Duration::from_secs(1));
         }
     }
-    fn disabled(&self, _: Context) {}
+    fn disabled(&self, _: Context) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 entry!(DelayBot);
@@ -6,18 +6,21 @@ use vex_rt::prelude::*;
 struct HelloBot;
 
 impl Robot for HelloBot {
-    fn initialize() -> Self {
+    fn initialize() -> Result<Self, Error> {
         println!("initialize");
-        HelloBot
+        Ok(HelloBot)
     }
-    fn autonomous(&self, _: Context) {
+    fn autonomous(&self, _: Context) -> Result<(), Error> {
         println!("autonomous");
+        Ok(())
     }
-    fn opcontrol(&self, _: Context) {
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
         println!("opcontrol");
+        Ok(())
     }
-    fn disabled(&self, _: Context) {
+    fn disabled(&self, _: Context) -> Result<(), Error> {
         println!("disabled");
+        Ok(())
     }
 }
 
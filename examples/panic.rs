@@ -6,12 +6,18 @@ use vex_rt::prelude::*;
 struct PanicBot;
 
 impl Robot for PanicBot {
-    fn initialize() -> Self {
+    fn initialize() -> Result<Self, Error> {
         panic!("Panic Message")
     }
-    fn autonomous(&self, _: Context) {}
-    fn opcontrol(&self, _: Context) {}
-    fn disabled(&self, _: Context) {}
+    fn autonomous(&self, _: Context) -> Result<(), Error> {
+        Ok(())
+    }
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
+        Ok(())
+    }
+    fn disabled(&self, _: Context) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 entry!(PanicBot);
@@ -10,10 +10,10 @@ use vex_rt::select;
 struct SelectRobot;
 
 impl Robot for SelectRobot {
-    fn initialize() -> Self {
-        Self
+    fn initialize() -> Result<Self, Error> {
+        Ok(Self)
     }
-    fn autonomous(&self, ctx: Context) {
+    fn autonomous(&self, ctx: Context) -> Result<(), Error> {
         println!("autonomous");
         let mut x = 0;
         let mut l = Loop::new(Duration::from_secs(1));
@@ -25,13 +25,16 @@ impl Robot for SelectRobot {
                 _ = ctx.done() => break,
             }
         }
-        println!("auto done")
+        println!("auto done");
+        Ok(())
     }
-    fn opcontrol(&self, _: Context) {
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
         println!("opcontrol");
+        Ok(())
     }
-    fn disabled(&self, _: Context) {
+    fn disabled(&self, _: Context) -> Result<(), Error> {
         println!("disabled");
+        Ok(())
     }
 }
 
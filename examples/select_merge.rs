@@ -11,10 +11,10 @@ use vex_rt::select_merge;
 struct SelectRobot;
 
 impl Robot for SelectRobot {
-    fn initialize() -> Self {
-        Self
+    fn initialize() -> Result<Self, Error> {
+        Ok(Self)
     }
-    fn autonomous(&self, ctx: Context) {
+    fn autonomous(&self, ctx: Context) -> Result<(), Error> {
         println!("autonomous");
         let mut x = 0;
         let mut l = Loop::new(Duration::from_secs(1));
@@ -29,13 +29,16 @@ impl Robot for SelectRobot {
                 break;
             }
         }
-        println!("auto done")
+        println!("auto done");
+        Ok(())
     }
-    fn opcontrol(&self, _: Context) {
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
         println!("opcontrol");
+        Ok(())
     }
-    fn disabled(&self, _: Context) {
+    fn disabled(&self, _: Context) -> Result<(), Error> {
         println!("disabled");
+        Ok(())
     }
 }
 
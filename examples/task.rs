@@ -9,7 +9,7 @@ use vex_rt::rtos::{Loop, Task};
 struct TaskBot;
 
 impl Robot for TaskBot {
-    fn initialize() -> Self {
+    fn initialize() -> Result<Self, Error> {
         let mut x = 0;
         let mut l = Loop::new(Duration::from_secs(1));
         Task::spawn_ext(
@@ -24,18 +24,20 @@ impl Robot for TaskBot {
                     l.delay()
                 }
             },
-        )
-        .unwrap();
-        TaskBot
+        )?;
+        Ok(TaskBot)
     }
-    fn autonomous(&self, _: Context) {
+    fn autonomous(&self, _: Context) -> Result<(), Error> {
         println!("autonomous");
+        Ok(())
     }
-    fn opcontrol(&self, _: Context) {
+    fn opcontrol(&self, _: Context) -> Result<(), Error> {
         println!("opcontrol");
+        Ok(())
     }
-    fn disabled(&self, _: Context) {
+    fn disabled(&self, _: Context) -> Result<(), Error> {
         println!("disabled");
+        Ok(())
     }
 }
 
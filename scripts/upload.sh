@@ -21,7 +21,7 @@ cat > project.pros << EOL
                 "system_files": [],
                 "target": "v5",
                 "user_files": [],
-                "version": "3.2.0"
+                "version": "3.8.0"
             }
         },
         "upload_options": {}
@@ -0,0 +1,545 @@
+//! ADI (three-wire) device API.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, Error},
+    rtos::{select_poll, Mutex, Selectable, Task},
+    units::Angle,
+};
+
+/// The delay between samples taken while calibrating an analog input.
+const CALIBRATION_SAMPLE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// How often a press event re-checks the state of a digital input. The ADI
+/// ports are only updated every 10 ms.
+const PRESS_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A struct which represents an ADI port configured as an analog input, such as
+/// a potentiometer or line tracker.
+pub struct AdiAnalogIn {
+    port: u8,
+    expander_port: u8,
+    /// The calibrated centre value, scaled by 16.
+    calibration: Option<i32>,
+}
+
+impl AdiAnalogIn {
+    /// Constructs a new analog input unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_analog_in()`] instead.
+    pub unsafe fn new(port: u8, expander_port: u8) -> Result<AdiAnalogIn, Error> {
+        configure(
+            port,
+            expander_port,
+            bindings::adi_port_config_e_E_ADI_ANALOG_IN,
+        )?;
+        Ok(AdiAnalogIn {
+            port,
+            expander_port,
+            calibration: None,
+        })
+    }
+
+    /// Reads the raw value of the input, on the range 0 to 4095.
+    pub fn read(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::ext_adi_analog_read(self.expander_port, self.port) })
+    }
+
+    /// Calibrates the input by averaging `samples` readings taken 1 ms apart,
+    /// returning the average. The input should be held still while this runs;
+    /// PROS itself uses 512 samples.
+    ///
+    /// Only the calibrated reads depend on calibration, and they fail until it
+    /// has been performed.
+    pub fn calibrate(&mut self, samples: u32) -> Result<i32, Error> {
+        if samples == 0 {
+            return Err(Error::Custom(
+                "analog calibration requires at least one sample".to_string(),
+            ));
+        }
+        let mut total: i64 = 0;
+        for _ in 0..samples {
+            total += self.read()? as i64;
+            Task::delay(CALIBRATION_SAMPLE_INTERVAL);
+        }
+        let calibration = ((total * 16 + samples as i64 / 2) / samples as i64) as i32;
+        self.calibration = Some(calibration);
+        Ok((calibration + 8) / 16)
+    }
+
+    /// Reads the value of the input relative to its calibrated value, on the
+    /// range -4095 to 4095.
+    pub fn read_calibrated(&self) -> Result<i32, Error> {
+        Ok(self.read()? - (self.get_calibration()? + 8) / 16)
+    }
+
+    /// Reads the value of the input relative to its calibrated value, with 4
+    /// extra bits of precision, on the range -65520 to 65520.
+    ///
+    /// Integrating this value is less prone to drift than integrating
+    /// [`AdiAnalogIn::read_calibrated()`], since the calibrated value is not
+    /// rounded to the nearest whole number.
+    pub fn read_calibrated_hr(&self) -> Result<i32, Error> {
+        Ok(self.read()? * 16 - self.get_calibration()?)
+    }
+
+    fn get_calibration(&self) -> Result<i32, Error> {
+        self.calibration
+            .ok_or_else(|| Error::Custom("analog input has not been calibrated".to_string()))
+    }
+}
+
+/// A struct which represents an ADI port configured as an analog output.
+pub struct AdiAnalogOut {
+    port: u8,
+    expander_port: u8,
+}
+
+impl AdiAnalogOut {
+    /// Constructs a new analog output unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_analog_out()`] instead.
+    pub unsafe fn new(port: u8, expander_port: u8) -> Result<AdiAnalogOut, Error> {
+        configure(
+            port,
+            expander_port,
+            bindings::adi_port_config_e_E_ADI_ANALOG_OUT,
+        )?;
+        Ok(AdiAnalogOut {
+            port,
+            expander_port,
+        })
+    }
+
+    /// Sets the value of the output, on the range 0 to 4095.
+    pub fn write(&self, value: i32) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::ext_adi_port_set_value(self.expander_port, self.port, value)
+        })?;
+        Ok(())
+    }
+}
+
+/// A struct which represents an ADI port configured as a digital input, such as
+/// a limit switch or bumper.
+pub struct AdiDigitalIn {
+    port: u8,
+    expander_port: u8,
+    /// The value of the input when a press event last checked it.
+    last: Mutex<bool>,
+}
+
+impl AdiDigitalIn {
+    /// Constructs a new digital input unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_digital_in()`] instead.
+    pub unsafe fn new(port: u8, expander_port: u8) -> Result<AdiDigitalIn, Error> {
+        configure(
+            port,
+            expander_port,
+            bindings::adi_port_config_e_E_ADI_DIGITAL_IN,
+        )?;
+        let input = AdiDigitalIn {
+            port,
+            expander_port,
+            last: Mutex::try_new(false)?,
+        };
+        *input.last.lock() = input.read()?;
+        Ok(input)
+    }
+
+    /// Reads the value of the input.
+    pub fn read(&self) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::ext_adi_digital_read(self.expander_port, self.port)
+        })? != 0)
+    }
+
+    /// Checks whether the input has changed from low to high since the last
+    /// time this function was called.
+    ///
+    /// The edge is tracked by PROS for each port, so calling this from
+    /// multiple places will cause presses to be missed.
+    pub fn new_press(&self) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::ext_adi_digital_get_new_press(self.expander_port, self.port)
+        })? != 0)
+    }
+
+    /// A [`Selectable`] event which occurs when the input changes from low to
+    /// high.
+    ///
+    /// The previous value of the input is kept between events, so no presses
+    /// are missed when a new event is created on each iteration of a loop.
+    /// Since every event shares it, only one task should wait for presses.
+    pub fn pressed<'a>(&'a self) -> impl Selectable<Result<(), Error>> + 'a {
+        select_poll(PRESS_POLL_INTERVAL, move || match self.read() {
+            Ok(value) => {
+                let mut last = self.last.lock();
+                let rising = value && !*last;
+                *last = value;
+                if rising {
+                    Some(Ok(()))
+                } else {
+                    None
+                }
+            }
+            Err(err) => Some(Err(err)),
+        })
+    }
+}
+
+/// A struct which represents an ADI port configured as a digital output.
+pub struct AdiDigitalOut {
+    port: u8,
+    expander_port: u8,
+}
+
+impl AdiDigitalOut {
+    /// Constructs a new digital output unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_digital_out()`] instead.
+    pub unsafe fn new(port: u8, expander_port: u8) -> Result<AdiDigitalOut, Error> {
+        configure(
+            port,
+            expander_port,
+            bindings::adi_port_config_e_E_ADI_DIGITAL_OUT,
+        )?;
+        Ok(AdiDigitalOut {
+            port,
+            expander_port,
+        })
+    }
+
+    /// Sets the value of the output.
+    pub fn write(&self, value: bool) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::ext_adi_digital_write(self.expander_port, self.port, value)
+        })?;
+        Ok(())
+    }
+}
+
+/// A struct which represents a pneumatic solenoid valve driven by an ADI port
+/// configured as a digital output.
+pub struct Pneumatic {
+    output: AdiDigitalOut,
+    extended: bool,
+}
+
+impl Pneumatic {
+    /// Creates a new pneumatic actuator using the given output, setting it to
+    /// the given initial state.
+    pub fn new(output: AdiDigitalOut, extended: bool) -> Result<Self, Error> {
+        output.write(extended)?;
+        Ok(Self { output, extended })
+    }
+
+    /// Extends the actuator.
+    pub fn extend(&mut self) -> Result<(), Error> {
+        self.set_extended(true)
+    }
+
+    /// Retracts the actuator.
+    pub fn retract(&mut self) -> Result<(), Error> {
+        self.set_extended(false)
+    }
+
+    /// Switches the actuator to the opposite state, returning the new state.
+    pub fn toggle(&mut self) -> Result<bool, Error> {
+        self.set_extended(!self.extended)?;
+        Ok(self.extended)
+    }
+
+    /// Extends or retracts the actuator.
+    pub fn set_extended(&mut self, extended: bool) -> Result<(), Error> {
+        self.output.write(extended)?;
+        self.extended = extended;
+        Ok(())
+    }
+
+    #[inline]
+    /// Checks whether the actuator was last commanded to extend.
+    pub fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+    #[inline]
+    /// Gets the underlying digital output.
+    pub fn into_inner(self) -> AdiDigitalOut {
+        self.output
+    }
+}
+
+/// A struct which represents a strip of addressable LEDs connected to an ADI
+/// port.
+///
+/// Colors are given as RGB values in the form `0xRRGGBB`. A copy of the color
+/// of every pixel is kept in a buffer, which is sent to the strip in full
+/// whenever any pixel changes.
+pub struct AdiLed {
+    handle: bindings::ext_adi_led_t,
+    buffer: Vec<u32>,
+}
+
+impl AdiLed {
+    /// The maximum number of pixels which can be driven by a single port.
+    pub const MAX_LENGTH: usize = 64;
+
+    /// Constructs a new LED strip of the given length unsafely. You probably
+    /// want to use [`crate::adi_port::AdiPort::as_led()`] instead.
+    pub unsafe fn new(port: u8, expander_port: u8, length: usize) -> Result<AdiLed, Error> {
+        if length == 0 || length > Self::MAX_LENGTH {
+            return Err(Error::Custom(format!(
+                "invalid LED strip length: {} (must be between 1 and {})",
+                length,
+                Self::MAX_LENGTH
+            )));
+        }
+        Ok(AdiLed {
+            handle: check_pros_err(bindings::ext_adi_led_init(expander_port, port))?,
+            buffer: vec![0; length],
+        })
+    }
+
+    #[inline]
+    /// Gets the number of pixels in the strip.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    /// Checks whether the strip has no pixels. This is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    #[inline]
+    /// Gets the last color set for every pixel in the strip.
+    pub fn pixels(&self) -> &[u32] {
+        &self.buffer
+    }
+
+    /// Sets the color of every pixel from `colors`, starting from the first
+    /// pixel. Pixels beyond the end of `colors` are left unchanged.
+    pub fn set(&mut self, colors: &[u32]) -> Result<(), Error> {
+        let n = colors.len().min(self.buffer.len());
+        self.buffer[..n].copy_from_slice(&colors[..n]);
+        check_pros_err(unsafe {
+            bindings::ext_adi_led_set(
+                self.handle,
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as u32,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Sets every pixel to the same color.
+    pub fn set_all(&mut self, color: u32) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::ext_adi_led_set_all(
+                self.handle,
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as u32,
+                color,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Sets the color of a single pixel.
+    pub fn set_pixel(&mut self, index: usize, color: u32) -> Result<(), Error> {
+        self.check_index(index)?;
+        check_pros_err(unsafe {
+            bindings::ext_adi_led_set_pixel(
+                self.handle,
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as u32,
+                color,
+                index as u32,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Turns off every pixel.
+    pub fn clear_all(&mut self) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::ext_adi_led_clear_all(
+                self.handle,
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as u32,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Turns off a single pixel.
+    pub fn clear_pixel(&mut self, index: usize) -> Result<(), Error> {
+        self.check_index(index)?;
+        check_pros_err(unsafe {
+            bindings::ext_adi_led_clear_pixel(
+                self.handle,
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as u32,
+                index as u32,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), Error> {
+        if index < self.buffer.len() {
+            Ok(())
+        } else {
+            Err(Error::Custom(format!(
+                "pixel index out of range: {} (length is {})",
+                index,
+                self.buffer.len()
+            )))
+        }
+    }
+}
+
+/// A struct which represents a legacy quadrature encoder connected to a pair of
+/// ADI ports.
+pub struct AdiEncoder {
+    handle: bindings::ext_adi_encoder_t,
+}
+
+impl AdiEncoder {
+    /// The number of ticks counted by the encoder per rotation.
+    pub const TICKS_PER_ROTATION: f64 = 360.0;
+
+    /// Constructs a new encoder unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_encoder()`] instead.
+    ///
+    /// `top` must be port A, C, E or G, and `bottom` must be the port after it.
+    pub unsafe fn new(
+        top: u8,
+        bottom: u8,
+        expander_port: u8,
+        reverse: bool,
+    ) -> Result<AdiEncoder, Error> {
+        check_pair(top, bottom)?;
+        Ok(AdiEncoder {
+            handle: check_pros_err(bindings::ext_adi_encoder_init(
+                expander_port,
+                top,
+                bottom,
+                reverse,
+            ))?,
+        })
+    }
+
+    /// Gets the number of ticks counted by the encoder since it was created or
+    /// last reset.
+    pub fn get(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::ext_adi_encoder_get(self.handle) })
+    }
+
+    /// Gets the angle turned by the encoder since it was created or last reset.
+    pub fn get_angle(&self) -> Result<Angle, Error> {
+        Ok(Angle::from_rotations(
+            self.get()? as f64 / Self::TICKS_PER_ROTATION,
+        ))
+    }
+
+    /// Resets the tick count of the encoder to zero.
+    pub fn reset(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::ext_adi_encoder_reset(self.handle) })?;
+        Ok(())
+    }
+}
+
+/// A struct which represents a legacy ultrasonic sensor connected to a pair of
+/// ADI ports.
+pub struct AdiUltrasonic {
+    handle: bindings::ext_adi_ultrasonic_t,
+}
+
+impl AdiUltrasonic {
+    /// Constructs a new ultrasonic sensor unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_ultrasonic()`] instead.
+    ///
+    /// `ping` (the orange output wire) must be port A, C, E or G, and `echo`
+    /// (the yellow input wire) must be the port after it.
+    pub unsafe fn new(ping: u8, echo: u8, expander_port: u8) -> Result<AdiUltrasonic, Error> {
+        check_pair(ping, echo)?;
+        Ok(AdiUltrasonic {
+            handle: check_pros_err(bindings::ext_adi_ultrasonic_init(expander_port, ping, echo))?,
+        })
+    }
+
+    /// Gets the distance to the nearest object in ten-thousandths of a meter,
+    /// as reported by PROS.
+    pub fn get(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::ext_adi_ultrasonic_get(self.handle) })
+    }
+}
+
+/// A struct which represents a legacy yaw-rate gyroscope connected to an ADI
+/// port.
+pub struct AdiGyro {
+    handle: bindings::ext_adi_gyro_t,
+}
+
+impl AdiGyro {
+    /// Constructs a new gyroscope unsafely. You probably want to use
+    /// [`crate::adi_port::AdiPort::as_gyro()`] instead.
+    ///
+    /// This blocks for approximately 1.3 seconds while the gyroscope
+    /// calibrates, during which the robot should not be moved. Readings are
+    /// scaled by `multiplier`, which should be `1.0` unless the gyroscope has
+    /// been found to drift by a constant factor.
+    pub unsafe fn new(port: u8, expander_port: u8, multiplier: f64) -> Result<AdiGyro, Error> {
+        Ok(AdiGyro {
+            handle: check_pros_err(bindings::ext_adi_gyro_init(expander_port, port, multiplier))?,
+        })
+    }
+
+    /// Gets the angle turned by the gyroscope since it was created or last
+    /// reset.
+    pub fn get(&self) -> Result<Angle, Error> {
+        Ok(Angle::from_degrees(
+            check_pros_err_f(unsafe { bindings::ext_adi_gyro_get(self.handle) })? / 10.0,
+        ))
+    }
+
+    /// Resets the angle of the gyroscope to zero.
+    pub fn reset(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::ext_adi_gyro_reset(self.handle) })?;
+        Ok(())
+    }
+}
+
+#[inline]
+unsafe fn configure(
+    port: u8,
+    expander_port: u8,
+    config: bindings::adi_port_config_e_t,
+) -> Result<(), Error> {
+    check_pros_err(bindings::ext_adi_port_set_config(
+        expander_port,
+        port,
+        config,
+    ))?;
+    Ok(())
+}
+
+fn check_pair(top: u8, bottom: u8) -> Result<(), Error> {
+    if top % 2 == 1 && bottom == top + 1 {
+        Ok(())
+    } else {
+        Err(Error::Custom(format!(
+            "ADI ports {} and {} do not form a valid pair",
+            port_name(top),
+            port_name(bottom)
+        )))
+    }
+}
+
+#[inline]
+fn port_name(port: u8) -> char {
+    (b'A' + port - 1) as char
+}
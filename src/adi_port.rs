@@ -0,0 +1,172 @@
+//! AdiPort.
+
+use alloc::string::ToString;
+
+use crate::{
+    adi::{
+        AdiAnalogIn, AdiAnalogOut, AdiDigitalIn, AdiDigitalOut, AdiEncoder, AdiGyro, AdiLed,
+        AdiUltrasonic, Pneumatic,
+    },
+    bindings,
+    error::Error,
+};
+
+#[derive(Debug)]
+/// A struct which represents an unconfigured ADI (three-wire) port.
+pub struct AdiPort {
+    port: u8,
+    expander_port: u8,
+}
+
+impl AdiPort {
+    /// Unsafely constructs a new ADI port on the brain.
+    pub unsafe fn new(port: u8) -> Self {
+        assert!(
+            (1..9).contains(&port),
+            "Cannot construct an ADI port on port {}",
+            port
+        );
+        Self {
+            port,
+            expander_port: bindings::INTERNAL_ADI_PORT as u8,
+        }
+    }
+
+    /// Unsafely constructs a new ADI port on the ADI expander connected to the
+    /// given smart port.
+    pub unsafe fn new_ext(port: u8, expander_port: u8) -> Self {
+        assert!(
+            (1..9).contains(&port),
+            "Cannot construct an ADI port on port {}",
+            port
+        );
+        assert!(
+            (1..22).contains(&expander_port),
+            "Cannot construct an ADI expander on smart port {}",
+            expander_port
+        );
+        Self {
+            port,
+            expander_port,
+        }
+    }
+
+    /// Converts an `AdiPort` into an [`AdiAnalogIn`](crate::adi::AdiAnalogIn).
+    pub fn as_analog_in(self) -> Result<AdiAnalogIn, Error> {
+        unsafe { AdiAnalogIn::new(self.port, self.expander_port) }
+    }
+
+    /// Converts an `AdiPort` into an
+    /// [`AdiAnalogOut`](crate::adi::AdiAnalogOut).
+    pub fn as_analog_out(self) -> Result<AdiAnalogOut, Error> {
+        unsafe { AdiAnalogOut::new(self.port, self.expander_port) }
+    }
+
+    /// Converts an `AdiPort` into an
+    /// [`AdiDigitalIn`](crate::adi::AdiDigitalIn).
+    pub fn as_digital_in(self) -> Result<AdiDigitalIn, Error> {
+        unsafe { AdiDigitalIn::new(self.port, self.expander_port) }
+    }
+
+    /// Converts an `AdiPort` into an
+    /// [`AdiDigitalOut`](crate::adi::AdiDigitalOut).
+    pub fn as_digital_out(self) -> Result<AdiDigitalOut, Error> {
+        unsafe { AdiDigitalOut::new(self.port, self.expander_port) }
+    }
+
+    /// Converts an `AdiPort` into a [`Pneumatic`](crate::adi::Pneumatic)
+    /// actuator in the given initial state.
+    pub fn as_pneumatic(self, extended: bool) -> Result<Pneumatic, Error> {
+        Pneumatic::new(self.as_digital_out()?, extended)
+    }
+
+    /// Converts an `AdiPort` into an [`AdiLed`](crate::adi::AdiLed) strip with
+    /// the given number of pixels.
+    pub fn as_led(self, length: usize) -> Result<AdiLed, Error> {
+        unsafe { AdiLed::new(self.port, self.expander_port, length) }
+    }
+
+    /// Converts a pair of `AdiPort`s into an
+    /// [`AdiEncoder`](crate::adi::AdiEncoder). `self` must be port A, C, E or
+    /// G, and `bottom` must be the port after it on the same device. On
+    /// failure, both ports are returned along with the error so that they can
+    /// still be used.
+    pub fn as_encoder(
+        self,
+        bottom: AdiPort,
+        reverse: bool,
+    ) -> Result<AdiEncoder, (Error, AdiPort, AdiPort)> {
+        self.check_same_device(&bottom)
+            .and_then(|()| unsafe {
+                AdiEncoder::new(self.port, bottom.port, self.expander_port, reverse)
+            })
+            .map_err(|err| (err, self, bottom))
+    }
+
+    /// Converts a pair of `AdiPort`s into an
+    /// [`AdiUltrasonic`](crate::adi::AdiUltrasonic). `self` is connected to the
+    /// ping (orange) wire and must be port A, C, E or G, and `echo` is
+    /// connected to the echo (yellow) wire and must be the port after it on the
+    /// same device. On failure, both ports are returned along with the error so
+    /// that they can still be used.
+    pub fn as_ultrasonic(self, echo: AdiPort) -> Result<AdiUltrasonic, (Error, AdiPort, AdiPort)> {
+        self.check_same_device(&echo)
+            .and_then(|()| unsafe { AdiUltrasonic::new(self.port, echo.port, self.expander_port) })
+            .map_err(|err| (err, self, echo))
+    }
+
+    /// Converts an `AdiPort` into an [`AdiGyro`](crate::adi::AdiGyro). This
+    /// blocks while the gyroscope calibrates; see
+    /// [`AdiGyro::new()`](crate::adi::AdiGyro::new()).
+    pub fn as_gyro(self, multiplier: f64) -> Result<AdiGyro, Error> {
+        unsafe { AdiGyro::new(self.port, self.expander_port, multiplier) }
+    }
+
+    fn check_same_device(&self, other: &AdiPort) -> Result<(), Error> {
+        if self.expander_port == other.expander_port {
+            Ok(())
+        } else {
+            Err(Error::Custom(
+                "paired ADI ports must be on the same device".to_string(),
+            ))
+        }
+    }
+}
+
+/// A struct which represents the ports of an ADI expander connected to a smart
+/// port.
+pub struct AdiExpander {
+    /// ADI Port A
+    pub port_a: AdiPort,
+    /// ADI Port B
+    pub port_b: AdiPort,
+    /// ADI Port C
+    pub port_c: AdiPort,
+    /// ADI Port D
+    pub port_d: AdiPort,
+    /// ADI Port E
+    pub port_e: AdiPort,
+    /// ADI Port F
+    pub port_f: AdiPort,
+    /// ADI Port G
+    pub port_g: AdiPort,
+    /// ADI Port H
+    pub port_h: AdiPort,
+}
+
+impl AdiExpander {
+    /// Constructs a new ADI expander unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_adi_expander()`] instead.
+    pub unsafe fn new(expander_port: u8) -> Self {
+        Self {
+            port_a: AdiPort::new_ext(1, expander_port),
+            port_b: AdiPort::new_ext(2, expander_port),
+            port_c: AdiPort::new_ext(3, expander_port),
+            port_d: AdiPort::new_ext(4, expander_port),
+            port_e: AdiPort::new_ext(5, expander_port),
+            port_f: AdiPort::new_ext(6, expander_port),
+            port_g: AdiPort::new_ext(7, expander_port),
+            port_h: AdiPort::new_ext(8, expander_port),
+        }
+    }
+}
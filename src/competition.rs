@@ -0,0 +1,124 @@
+//! Competition status API.
+
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    rtos::{select_poll, Selectable},
+};
+
+/// How often a mode change event re-checks the competition status.
+const MODE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents the current competition mode of the robot.
+pub enum CompetitionMode {
+    /// The robot is disabled.
+    Disabled,
+    /// The robot is in the autonomous period.
+    Autonomous,
+    /// The robot is in the driver control period.
+    Opcontrol,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents the kind of competition control system the robot is connected
+/// to.
+pub enum CompetitionSystem {
+    /// A VEX field controller.
+    FieldControl,
+    /// A competition switch.
+    CompetitionSwitch,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents a snapshot of the competition status of the robot.
+pub struct CompetitionStatus {
+    /// The current competition mode.
+    pub mode: CompetitionMode,
+    /// The competition control system the robot is connected to, if any.
+    pub system: Option<CompetitionSystem>,
+}
+
+impl CompetitionStatus {
+    fn from_bits(bits: u32) -> Self {
+        Self {
+            mode: if bits & bindings::COMPETITION_DISABLED != 0 {
+                CompetitionMode::Disabled
+            } else if bits & bindings::COMPETITION_AUTONOMOUS != 0 {
+                CompetitionMode::Autonomous
+            } else {
+                CompetitionMode::Opcontrol
+            },
+            system: if bits & bindings::COMPETITION_CONNECTED == 0 {
+                None
+            } else if bits & bindings::COMPETITION_SYSTEM != 0 {
+                Some(CompetitionSystem::FieldControl)
+            } else {
+                Some(CompetitionSystem::CompetitionSwitch)
+            },
+        }
+    }
+}
+
+#[inline]
+/// Gets the current competition status of the robot.
+pub fn get_status() -> CompetitionStatus {
+    CompetitionStatus::from_bits(unsafe { bindings::competition_get_status() } as u32)
+}
+
+#[inline]
+/// Checks whether the robot is in the autonomous period.
+pub fn is_autonomous() -> bool {
+    unsafe { bindings::competition_is_autonomous() != 0 }
+}
+
+#[inline]
+/// Checks whether the robot is disabled.
+pub fn is_disabled() -> bool {
+    unsafe { bindings::competition_is_disabled() != 0 }
+}
+
+#[inline]
+/// Checks whether the robot is connected to a competition control system.
+pub fn is_connected() -> bool {
+    unsafe { bindings::competition_is_connected() != 0 }
+}
+
+#[inline]
+/// Gets the competition control system the robot is connected to, if any.
+pub fn get_system() -> Option<CompetitionSystem> {
+    get_status().system
+}
+
+/// A [`Selectable`] event which occurs when the competition mode differs from
+/// `from`, producing the new status.
+///
+/// Passing the last mode which was seen, rather than the mode when the event is
+/// created, means that no change is missed when a new event is created on each
+/// iteration of a loop.
+///
+/// # Examples
+///
+/// ```
+/// let mut status = competition::get_status();
+/// loop {
+///     select! {
+///         s = competition::mode_change(status.mode) => {
+///             println!("mode changed: {:?} -> {:?}", status.mode, s.mode);
+///             status = s;
+///         },
+///         _ = ctx.done() => break,
+///     }
+/// }
+/// ```
+pub fn mode_change(from: CompetitionMode) -> impl Selectable<CompetitionStatus> {
+    select_poll(MODE_POLL_INTERVAL, move || {
+        let status = get_status();
+        if status.mode != from {
+            Some(status)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,392 @@
+//! Controller API.
+
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+};
+use core::{fmt, time::Duration};
+use spin::once::Once;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, Error},
+    rtos::{select_poll, Loop, Mutex, Selectable, Task},
+    util::as_cstring,
+};
+
+/// How often a press event re-checks the state of a button.
+const PRESS_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// The minimum time between writes to a controller, as enforced by PROS.
+const WRITE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The maximum number of rumble patterns waiting to be sent to a controller.
+const MAX_QUEUED_RUMBLES: usize = 8;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents one of the two controllers which can be connected to the brain.
+pub enum ControllerId {
+    /// The master (primary) controller.
+    Master,
+    /// The partner (secondary) controller.
+    Partner,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents a joystick axis on a controller.
+pub enum Axis {
+    /// The horizontal axis of the left joystick.
+    LeftX,
+    /// The vertical axis of the left joystick.
+    LeftY,
+    /// The horizontal axis of the right joystick.
+    RightX,
+    /// The vertical axis of the right joystick.
+    RightY,
+}
+
+impl Axis {
+    fn to_controller_analog_e_t(self) -> bindings::controller_analog_e_t {
+        match self {
+            Axis::LeftX => bindings::controller_analog_e_t_E_CONTROLLER_ANALOG_LEFT_X,
+            Axis::LeftY => bindings::controller_analog_e_t_E_CONTROLLER_ANALOG_LEFT_Y,
+            Axis::RightX => bindings::controller_analog_e_t_E_CONTROLLER_ANALOG_RIGHT_X,
+            Axis::RightY => bindings::controller_analog_e_t_E_CONTROLLER_ANALOG_RIGHT_Y,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents a button on a controller.
+pub enum Button {
+    /// The upper left shoulder button.
+    L1,
+    /// The lower left shoulder button.
+    L2,
+    /// The upper right shoulder button.
+    R1,
+    /// The lower right shoulder button.
+    R2,
+    /// The up arrow button.
+    Up,
+    /// The down arrow button.
+    Down,
+    /// The left arrow button.
+    Left,
+    /// The right arrow button.
+    Right,
+    /// The X button.
+    X,
+    /// The B button.
+    B,
+    /// The Y button.
+    Y,
+    /// The A button.
+    A,
+}
+
+impl Button {
+    /// The number of buttons on a controller.
+    const COUNT: usize = 12;
+
+    fn to_controller_digital_e_t(self) -> bindings::controller_digital_e_t {
+        match self {
+            Button::L1 => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_L1,
+            Button::L2 => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_L2,
+            Button::R1 => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_R1,
+            Button::R2 => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_R2,
+            Button::Up => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_UP,
+            Button::Down => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_DOWN,
+            Button::Left => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_LEFT,
+            Button::Right => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_RIGHT,
+            Button::X => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_X,
+            Button::B => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_B,
+            Button::Y => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_Y,
+            Button::A => bindings::controller_digital_e_t_E_CONTROLLER_DIGITAL_A,
+        }
+    }
+}
+
+/// A struct which represents a V5 controller.
+///
+/// Writes to the controller's screen and rumble motor are rate-limited by PROS,
+/// so they are queued and sent by a background task, which is started on the
+/// first write. The screen's contents are kept in a buffer which writes update
+/// immediately, and each changed line is sent to the controller in full, so
+/// writes made faster than the controller accepts them are merged rather than
+/// lost.
+pub struct Controller {
+    id: bindings::controller_id_e_t,
+    /// The state of each button when a press event last checked it, or `None`
+    /// if no press event has checked it yet.
+    buttons: Mutex<[Option<bool>; Button::COUNT]>,
+    screen: Once<Arc<Mutex<ScreenState>>>,
+}
+
+impl Controller {
+    /// The number of lines on the controller's screen.
+    pub const SCREEN_LINES: u8 = 3;
+
+    /// The number of characters on each line of the controller's screen.
+    pub const SCREEN_COLUMNS: u8 = 19;
+
+    /// Constructs a new controller unsafely. You probably want to use
+    /// [`crate::peripherals::Peripherals`] instead.
+    pub unsafe fn new(id: ControllerId) -> Controller {
+        Controller {
+            id: match id {
+                ControllerId::Master => bindings::controller_id_e_t_E_CONTROLLER_MASTER,
+                ControllerId::Partner => bindings::controller_id_e_t_E_CONTROLLER_PARTNER,
+            },
+            buttons: Mutex::new([None; Button::COUNT]),
+            screen: Once::new(),
+        }
+    }
+
+    /// Checks whether the controller is connected.
+    pub fn is_connected(&self) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe { bindings::controller_is_connected(self.id) })? != 0)
+    }
+
+    /// Gets the position of a joystick axis, on the range -127 to 127.
+    pub fn get_analog(&self, axis: Axis) -> Result<i8, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::controller_get_analog(self.id, axis.to_controller_analog_e_t())
+        })? as i8)
+    }
+
+    /// Checks whether a button is currently pressed.
+    pub fn get_digital(&self, button: Button) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::controller_get_digital(self.id, button.to_controller_digital_e_t())
+        })? != 0)
+    }
+
+    /// Checks whether a button has been pressed since the last time this
+    /// function was called for that button.
+    ///
+    /// The edge is tracked by PROS for each button, so calling this from
+    /// multiple places will cause presses to be missed; prefer
+    /// [`Controller::pressed()`] in that case.
+    pub fn get_digital_new_press(&self, button: Button) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::controller_get_digital_new_press(self.id, button.to_controller_digital_e_t())
+        })? != 0)
+    }
+
+    /// Gets the battery capacity of the controller.
+    pub fn get_battery_capacity(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::controller_get_battery_capacity(self.id) })
+    }
+
+    /// Gets the battery level of the controller.
+    pub fn get_battery_level(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::controller_get_battery_level(self.id) })
+    }
+
+    /// A [`Selectable`] event which occurs when a button is pressed.
+    ///
+    /// The previous state of each button is kept between events, so no presses
+    /// are missed when a new event is created on each iteration of a loop.
+    /// Since every event for a button shares it, only one task should wait for
+    /// presses of each button. A button which is already held the first time
+    /// it is checked does not count as pressed until it is released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut l = Loop::new(Duration::from_millis(10));
+    /// loop {
+    ///     select! {
+    ///         _ = l.next() => drive(&controller),
+    ///         r = controller.pressed(Button::A) => r?,
+    ///         _ = ctx.done() => break,
+    ///     }
+    /// }
+    /// ```
+    pub fn pressed<'a>(&'a self, button: Button) -> impl Selectable<Result<(), Error>> + 'a {
+        select_poll(PRESS_POLL_INTERVAL, move || {
+            match self.get_digital(button) {
+                Ok(value) => {
+                    let mut buttons = self.buttons.lock();
+                    let rising = value && buttons[button as usize] == Some(false);
+                    buttons[button as usize] = Some(value);
+                    if rising {
+                        Some(Ok(()))
+                    } else {
+                        None
+                    }
+                }
+                Err(err) => Some(Err(err)),
+            }
+        })
+    }
+
+    /// Prints formatted text to the controller's screen, starting at the given
+    /// line and column. Lines are numbered from 0 to 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// controller.print(0, 0, format_args!("battery: {}%", level))?;
+    /// ```
+    pub fn print(&self, line: u8, col: u8, args: fmt::Arguments<'_>) -> Result<(), Error> {
+        self.set_text(line, col, &format!("{}", args))
+    }
+
+    /// Writes text to the controller's screen, starting at the given line and
+    /// column. Lines are numbered from 0 to 2. Text which does not fit on the
+    /// line is cut off, and characters which the controller cannot display are
+    /// shown as `?`.
+    pub fn set_text(&self, line: u8, col: u8, text: &str) -> Result<(), Error> {
+        check_line(line)?;
+        self.with_screen(|state| {
+            let buf = &mut state.lines[line as usize];
+            let start = buf.len().min(col as usize);
+            for (b, c) in buf[start..].iter_mut().zip(text.chars()) {
+                *b = if c.is_ascii() && !c.is_ascii_control() {
+                    c as u8
+                } else {
+                    b'?'
+                };
+            }
+            state.dirty[line as usize] = true;
+        })
+    }
+
+    /// Clears a single line of the controller's screen.
+    pub fn clear_line(&self, line: u8) -> Result<(), Error> {
+        check_line(line)?;
+        self.with_screen(|state| state.clear_line(line as usize))
+    }
+
+    /// Clears the controller's screen.
+    pub fn clear(&self) -> Result<(), Error> {
+        self.with_screen(|state| {
+            for line in 0..state.lines.len() {
+                state.clear_line(line);
+            }
+        })
+    }
+
+    /// Queues a rumble pattern. The pattern consists of up to 8 characters,
+    /// where `.` is a short rumble, `-` is a long rumble and ` ` is a pause.
+    /// Patterns are played in the order they were queued.
+    pub fn rumble(&self, pattern: &str) -> Result<(), Error> {
+        if pattern.is_empty()
+            || pattern.len() > 8
+            || !pattern.chars().all(|c| c == '.' || c == '-' || c == ' ')
+        {
+            return Err(Error::Custom(format!(
+                "invalid rumble pattern: {:?}",
+                pattern
+            )));
+        }
+        self.with_screen(|state| {
+            if state.rumbles.len() < MAX_QUEUED_RUMBLES {
+                state.rumbles.push_back(pattern.to_string());
+                Ok(())
+            } else {
+                Err(Error::Custom("too many rumble patterns queued".to_string()))
+            }
+        })?
+    }
+
+    fn with_screen<T>(&self, f: impl FnOnce(&mut ScreenState) -> T) -> Result<T, Error> {
+        let screen = self
+            .screen
+            .call_once(|| Arc::new(Mutex::new(ScreenState::new())));
+        let mut state = screen.lock();
+        if !state.task_started {
+            let id = self.id;
+            let screen = screen.clone();
+            Task::spawn(move || run_screen(id, screen))?;
+            state.task_started = true;
+        }
+        Ok(f(&mut state))
+    }
+}
+
+type Line = [u8; Controller::SCREEN_COLUMNS as usize];
+
+struct ScreenState {
+    /// The text which should be shown on each line of the screen.
+    lines: [Line; Controller::SCREEN_LINES as usize],
+    /// Whether each line has changed since it was last sent.
+    dirty: [bool; Controller::SCREEN_LINES as usize],
+    rumbles: VecDeque<String>,
+    next_line: usize,
+    task_started: bool,
+}
+
+impl ScreenState {
+    fn new() -> Self {
+        Self {
+            lines: [[b' '; Controller::SCREEN_COLUMNS as usize]; Controller::SCREEN_LINES as usize],
+            dirty: [false; Controller::SCREEN_LINES as usize],
+            rumbles: VecDeque::new(),
+            next_line: 0,
+            task_started: false,
+        }
+    }
+
+    fn clear_line(&mut self, line: usize) {
+        self.lines[line] = [b' '; Controller::SCREEN_COLUMNS as usize];
+        self.dirty[line] = true;
+    }
+
+    /// Sends at most one pending update to the controller, returning whether
+    /// there was anything to send. Updates which fail are kept to be retried.
+    fn send_next(&mut self, id: bindings::controller_id_e_t) -> bool {
+        if let Some(pattern) = self.rumbles.front() {
+            if as_cstring(pattern.as_str(), |cpattern| {
+                check_pros_err(unsafe { bindings::controller_rumble(id, cpattern.into_raw()) })
+            })
+            .is_ok()
+            {
+                self.rumbles.pop_front();
+            }
+            return true;
+        }
+        // Take turns between lines, so that a frequently updated line does not
+        // starve the others.
+        for i in 0..self.lines.len() {
+            let line = (self.next_line + i) % self.lines.len();
+            if self.dirty[line] {
+                // The buffer only ever holds printable ASCII, so it is valid
+                // UTF-8 without any interior nul bytes.
+                let text = core::str::from_utf8(&self.lines[line]).unwrap_or_default();
+                // The whole line is written, so that spaces overwrite whatever
+                // the controller was showing before.
+                let r = as_cstring(text, |ctext| {
+                    check_pros_err(unsafe {
+                        bindings::controller_set_text(id, line as u8, 0, ctext.into_raw())
+                    })
+                });
+                self.dirty[line] = r.is_err();
+                self.next_line = line + 1;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn run_screen(id: bindings::controller_id_e_t, screen: Arc<Mutex<ScreenState>>) {
+    let mut l = Loop::new(WRITE_INTERVAL);
+    // Stop once the controller has been dropped and nothing is left to send.
+    while screen.lock().send_next(id) || Arc::strong_count(&screen) > 1 {
+        l.delay();
+    }
+}
+
+#[inline]
+fn check_line(line: u8) -> Result<(), Error> {
+    if line < Controller::SCREEN_LINES {
+        Ok(())
+    } else {
+        Err(Error::Custom(format!("invalid controller line: {}", line)))
+    }
+}
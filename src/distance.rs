@@ -0,0 +1,41 @@
+//! Distance sensor API.
+
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, Error},
+};
+
+/// A struct which represents a V5 smart port configured as a distance sensor.
+pub struct DistanceSensor {
+    port: u8,
+}
+
+impl DistanceSensor {
+    /// Constructs a new distance sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_distance_sensor()`] instead.
+    pub unsafe fn new(port: u8) -> DistanceSensor {
+        assert!((1..22).contains(&port));
+        DistanceSensor { port }
+    }
+
+    /// Gets the distance to the detected object in millimeters.
+    pub fn get_distance(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::distance_get(self.port) })
+    }
+
+    /// Gets the confidence of the distance reading, on the range 0 to 63. The
+    /// confidence is only meaningful for distances greater than 200 mm.
+    pub fn get_confidence(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::distance_get_confidence(self.port) })
+    }
+
+    /// Gets the apparent size of the detected object, on the range 0 to 400.
+    pub fn get_object_size(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::distance_get_object_size(self.port) })
+    }
+
+    /// Gets the velocity of the detected object in meters per second.
+    pub fn get_object_velocity(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::distance_get_object_velocity(self.port) })
+    }
+}
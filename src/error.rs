@@ -68,3 +68,33 @@ pub fn get_errno() -> libc::c_int {
 pub fn from_errno() -> Error {
     Error::System(get_errno())
 }
+
+/// The value returned by PROS functions with an integer result to indicate an
+/// error.
+pub(crate) const PROS_ERR: i32 = i32::MAX;
+
+/// The value returned by PROS functions with a floating-point result to
+/// indicate an error.
+pub(crate) const PROS_ERR_F: f64 = f64::INFINITY;
+
+#[inline]
+/// Checks an integer result from a PROS function, generating an [`Error`] from
+/// `errno` if it is [`PROS_ERR`].
+pub(crate) fn check_pros_err(value: i32) -> Result<i32, Error> {
+    if value == PROS_ERR {
+        Err(from_errno())
+    } else {
+        Ok(value)
+    }
+}
+
+#[inline]
+/// Checks a floating-point result from a PROS function, generating an [`Error`]
+/// from `errno` if it is [`PROS_ERR_F`].
+pub(crate) fn check_pros_err_f(value: f64) -> Result<f64, Error> {
+    if value == PROS_ERR_F {
+        Err(from_errno())
+    } else {
+        Ok(value)
+    }
+}
@@ -0,0 +1,157 @@
+//! GPS sensor API.
+
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, Error},
+    imu::{degrees, vector, InertialVector},
+    units::Angle,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents the position and orientation of a GPS sensor on the field.
+pub struct GpsStatus {
+    /// The x position in meters, relative to the center of the field.
+    pub x: f64,
+    /// The y position in meters, relative to the center of the field.
+    pub y: f64,
+    /// The pitch angle.
+    pub pitch: Angle,
+    /// The roll angle.
+    pub roll: Angle,
+    /// The yaw angle.
+    pub yaw: Angle,
+}
+
+/// A struct which represents a V5 smart port configured as a GPS sensor.
+pub struct GpsSensor {
+    port: u8,
+}
+
+impl GpsSensor {
+    /// Constructs a new GPS sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_gps()`] instead.
+    pub unsafe fn new(port: u8) -> GpsSensor {
+        assert!((1..22).contains(&port));
+        GpsSensor { port }
+    }
+
+    /// Sets the initial position and heading of the sensor, and its offset
+    /// from the center of rotation of the robot, in meters.
+    pub fn initialize(
+        &self,
+        x: f64,
+        y: f64,
+        heading: Angle,
+        x_offset: f64,
+        y_offset: f64,
+    ) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::gps_initialize_full(self.port, x, y, heading.as_degrees(), x_offset, y_offset)
+        })?;
+        Ok(())
+    }
+
+    /// Sets the offset of the sensor from the center of rotation of the robot,
+    /// in meters.
+    pub fn set_offset(&self, x_offset: f64, y_offset: f64) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::gps_set_offset(self.port, x_offset, y_offset) })?;
+        Ok(())
+    }
+
+    /// Gets the offset of the sensor from the center of rotation of the robot,
+    /// in meters, as `(x, y)`.
+    pub fn get_offset(&self) -> Result<(f64, f64), Error> {
+        let mut x_offset = 0.0;
+        let mut y_offset = 0.0;
+        check_pros_err(unsafe {
+            bindings::gps_get_offset(self.port, &mut x_offset, &mut y_offset)
+        })?;
+        Ok((x_offset, y_offset))
+    }
+
+    /// Sets the position of the sensor in meters, and its heading.
+    pub fn set_position(&self, x: f64, y: f64, heading: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::gps_set_position(self.port, x, y, heading.as_degrees())
+        })?;
+        Ok(())
+    }
+
+    /// Sets the interval at which the sensor reports new data. The interval is
+    /// rounded down to a multiple of 5 ms, with a minimum of 5 ms.
+    pub fn set_data_rate(&self, rate: Duration) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::gps_set_data_rate(self.port, rate.as_millis() as u32) })?;
+        Ok(())
+    }
+
+    /// Gets the root mean squared error of the sensor's position estimate, in
+    /// meters.
+    pub fn get_error(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::gps_get_error(self.port) })
+    }
+
+    /// Gets the position and orientation of the sensor.
+    pub fn get_status(&self) -> Result<GpsStatus, Error> {
+        let status = unsafe { bindings::gps_get_status(self.port) };
+        Ok(GpsStatus {
+            x: check_pros_err_f(status.x)?,
+            y: check_pros_err_f(status.y)?,
+            pitch: Angle::from_degrees(status.pitch),
+            roll: Angle::from_degrees(status.roll),
+            yaw: Angle::from_degrees(status.yaw),
+        })
+    }
+
+    /// Gets the heading of the sensor, on the range 0° to 360°.
+    pub fn get_heading(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::gps_get_heading(self.port) })
+    }
+
+    /// Gets the heading of the sensor without wrapping, on the range -240° to
+    /// 240°.
+    pub fn get_heading_raw(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::gps_get_heading_raw(self.port) })
+    }
+
+    /// Gets the total rotation of the sensor, which is not bounded to a single
+    /// revolution.
+    pub fn get_rotation(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::gps_get_rotation(self.port) })
+    }
+
+    /// Sets the rotation of the sensor.
+    pub fn set_rotation(&self, rotation: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::gps_set_rotation(self.port, rotation.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Resets the rotation of the sensor to zero.
+    pub fn tare_rotation(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::gps_tare_rotation(self.port) })?;
+        Ok(())
+    }
+
+    /// Gets the raw rate of rotation reported by the gyroscope, in degrees per
+    /// second.
+    pub fn get_gyro_rate(&self) -> Result<InertialVector, Error> {
+        vector(unsafe { bindings::gps_get_gyro_rate(self.port) })
+    }
+
+    /// Gets the raw acceleration reported by the accelerometer, in multiples of
+    /// standard gravity (g).
+    pub fn get_accel(&self) -> Result<InertialVector, Error> {
+        vector(unsafe { bindings::gps_get_accel(self.port) })
+    }
+}
+
+impl From<bindings::gps_raw_s> for InertialVector {
+    fn from(raw: bindings::gps_raw_s) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+            z: raw.z,
+        }
+    }
+}
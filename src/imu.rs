@@ -0,0 +1,281 @@
+//! Inertial sensor API.
+
+use alloc::string::ToString;
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, from_errno, Error, PROS_ERR_F},
+    rtos::{select_poll, time_since_start, Selectable},
+    units::Angle,
+};
+
+/// How often a calibration event re-checks the status of the sensor.
+const CALIBRATION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long a calibration event waits for the sensor to report that
+/// calibration has started.
+const CALIBRATION_START_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents an orientation as a quaternion.
+pub struct Quaternion {
+    /// The x component.
+    pub x: f64,
+    /// The y component.
+    pub y: f64,
+    /// The z component.
+    pub z: f64,
+    /// The w (scalar) component.
+    pub w: f64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents an orientation as a set of Euler angles.
+pub struct Euler {
+    /// The pitch angle, on the range -180° to 180°.
+    pub pitch: Angle,
+    /// The roll angle, on the range -180° to 180°.
+    pub roll: Angle,
+    /// The yaw angle, on the range -180° to 180°.
+    pub yaw: Angle,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents a raw three-axis reading from the sensor.
+pub struct InertialVector {
+    /// The x component.
+    pub x: f64,
+    /// The y component.
+    pub y: f64,
+    /// The z component.
+    pub z: f64,
+}
+
+/// A struct which represents a V5 smart port configured as an inertial sensor.
+pub struct InertialSensor {
+    port: u8,
+}
+
+impl InertialSensor {
+    /// Constructs a new inertial sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_imu()`] instead.
+    pub unsafe fn new(port: u8) -> InertialSensor {
+        assert!((1..22).contains(&port));
+        InertialSensor { port }
+    }
+
+    /// Starts calibrating the sensor, returning a [`Selectable`] event which
+    /// occurs when calibration is complete. Calibration takes approximately 2
+    /// seconds, during which the robot should not be moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let calibration = imu.calibrate()?;
+    /// select! {
+    ///     r = calibration => r?,
+    ///     _ = ctx.done() => return Ok(()),
+    /// }
+    /// ```
+    pub fn calibrate<'a>(&'a self) -> Result<impl Selectable<Result<(), Error>> + 'a, Error> {
+        check_pros_err(unsafe { bindings::imu_reset(self.port) })?;
+        let start = time_since_start();
+        let mut started = false;
+        Ok(select_poll(CALIBRATION_POLL_INTERVAL, move || {
+            match self.is_calibrating() {
+                Ok(true) => started = true,
+                Ok(false) if started => return Some(Ok(())),
+                Ok(false) if time_since_start() >= start + CALIBRATION_START_TIMEOUT => {
+                    return Some(Err(Error::Custom(
+                        "inertial sensor calibration did not start".to_string(),
+                    )))
+                }
+                Ok(false) => {}
+                Err(err) => return Some(Err(err)),
+            }
+            None
+        }))
+    }
+
+    /// Checks whether the sensor is currently calibrating.
+    pub fn is_calibrating(&self) -> Result<bool, Error> {
+        let status = unsafe { bindings::imu_get_status(self.port) };
+        if status == bindings::imu_status_e_E_IMU_STATUS_ERROR {
+            Err(from_errno())
+        } else {
+            Ok(status & bindings::imu_status_e_E_IMU_STATUS_CALIBRATING != 0)
+        }
+    }
+
+    /// Gets the total rotation of the sensor about the vertical axis since the
+    /// last calibration or tare, which is not bounded to a single revolution.
+    pub fn get_rotation(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::imu_get_rotation(self.port) })
+    }
+
+    /// Gets the heading of the sensor, on the range 0° to 360°.
+    pub fn get_heading(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::imu_get_heading(self.port) })
+    }
+
+    /// Gets the pitch of the sensor, on the range -180° to 180°.
+    pub fn get_pitch(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::imu_get_pitch(self.port) })
+    }
+
+    /// Gets the roll of the sensor, on the range -180° to 180°.
+    pub fn get_roll(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::imu_get_roll(self.port) })
+    }
+
+    /// Gets the yaw of the sensor, on the range -180° to 180°.
+    pub fn get_yaw(&self) -> Result<Angle, Error> {
+        degrees(unsafe { bindings::imu_get_yaw(self.port) })
+    }
+
+    /// Gets the orientation of the sensor as a set of Euler angles.
+    pub fn get_euler(&self) -> Result<Euler, Error> {
+        let e = unsafe { bindings::imu_get_euler(self.port) };
+        Ok(Euler {
+            pitch: degrees(e.pitch)?,
+            roll: degrees(e.roll)?,
+            yaw: degrees(e.yaw)?,
+        })
+    }
+
+    /// Gets the orientation of the sensor as a quaternion.
+    pub fn get_quaternion(&self) -> Result<Quaternion, Error> {
+        let q = unsafe { bindings::imu_get_quaternion(self.port) };
+        Ok(Quaternion {
+            x: check_pros_err_f(q.x)?,
+            y: check_pros_err_f(q.y)?,
+            z: check_pros_err_f(q.z)?,
+            w: check_pros_err_f(q.w)?,
+        })
+    }
+
+    /// Gets the raw rate of rotation reported by the gyroscope, in degrees per
+    /// second.
+    pub fn get_gyro_rate(&self) -> Result<InertialVector, Error> {
+        vector(unsafe { bindings::imu_get_gyro_rate(self.port) })
+    }
+
+    /// Gets the raw acceleration reported by the accelerometer, in multiples of
+    /// standard gravity (g).
+    pub fn get_accel(&self) -> Result<InertialVector, Error> {
+        vector(unsafe { bindings::imu_get_accel(self.port) })
+    }
+
+    /// Resets every reading of the sensor to zero.
+    pub fn tare(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the heading of the sensor to zero.
+    pub fn tare_heading(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_heading(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the rotation of the sensor to zero.
+    pub fn tare_rotation(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_rotation(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the pitch of the sensor to zero.
+    pub fn tare_pitch(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_pitch(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the roll of the sensor to zero.
+    pub fn tare_roll(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_roll(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the yaw of the sensor to zero.
+    pub fn tare_yaw(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_yaw(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the pitch, roll and yaw of the sensor to zero.
+    pub fn tare_euler(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_tare_euler(self.port) })?;
+        Ok(())
+    }
+
+    /// Sets the heading of the sensor.
+    pub fn set_heading(&self, heading: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_set_heading(self.port, heading.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Sets the rotation of the sensor.
+    pub fn set_rotation(&self, rotation: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_set_rotation(self.port, rotation.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Sets the pitch of the sensor.
+    pub fn set_pitch(&self, pitch: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_set_pitch(self.port, pitch.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Sets the roll of the sensor.
+    pub fn set_roll(&self, roll: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_set_roll(self.port, roll.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Sets the yaw of the sensor.
+    pub fn set_yaw(&self, yaw: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::imu_set_yaw(self.port, yaw.as_degrees()) })?;
+        Ok(())
+    }
+
+    /// Sets the pitch, roll and yaw of the sensor.
+    pub fn set_euler(&self, euler: Euler) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::imu_set_euler(
+                self.port,
+                bindings::euler_s_t {
+                    pitch: euler.pitch.as_degrees(),
+                    roll: euler.roll.as_degrees(),
+                    yaw: euler.yaw.as_degrees(),
+                },
+            )
+        })?;
+        Ok(())
+    }
+}
+
+impl From<bindings::imu_raw_s> for InertialVector {
+    fn from(raw: bindings::imu_raw_s) -> Self {
+        Self {
+            x: raw.x,
+            y: raw.y,
+            z: raw.z,
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn degrees(value: f64) -> Result<Angle, Error> {
+    Ok(Angle::from_degrees(check_pros_err_f(value)?))
+}
+
+#[inline]
+pub(crate) fn vector(raw: impl Into<InertialVector>) -> Result<InertialVector, Error> {
+    let v = raw.into();
+    if v.x == PROS_ERR_F || v.y == PROS_ERR_F || v.z == PROS_ERR_F {
+        Err(from_errno())
+    } else {
+        Ok(v)
+    }
+}
@@ -11,17 +11,30 @@ use core::panic::PanicInfo;
 
 mod allocator;
 mod bindings;
-mod error;
 mod util;
 
+pub mod adi;
+pub mod adi_port;
+pub mod competition;
+pub mod controller;
+pub mod distance;
+pub mod error;
+pub mod gps;
+pub mod imu;
 pub mod io;
+pub mod link;
 pub mod macros;
 pub mod motor;
+pub mod optical;
 pub mod peripherals;
 pub mod prelude;
 pub mod robot;
+pub mod rotation;
 pub mod rtos;
+pub mod serial;
 pub mod smart_port;
+pub mod units;
+pub mod vision;
 
 #[doc(hidden)]
 pub use spin::once;
@@ -0,0 +1,251 @@
+//! VEXlink radio API.
+
+use alloc::{format, string::ToString, vec::Vec};
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, Error},
+    rtos::{select_poll, Selectable},
+    util::as_cstring,
+};
+
+/// How often a message event re-checks the receive buffer.
+const MESSAGE_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// The byte which marks the start of a frame sent by a [`MessageChannel`].
+const FRAME_START: u8 = 0x5a;
+
+/// The number of bytes added to each message by a [`MessageChannel`]: the
+/// start byte, a two-byte length and a two-byte checksum.
+const FRAME_OVERHEAD: usize = 5;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// An enum which represents the role of a radio in a VEXlink connection.
+pub enum LinkType {
+    /// The radio receives the connection.
+    Receiver,
+    /// The radio initiates the connection.
+    Transmitter,
+}
+
+/// A struct which represents a V5 smart port configured as a VEXlink radio.
+pub struct Link {
+    port: u8,
+}
+
+impl Link {
+    /// The size of the radio's transmit and receive buffers in bytes.
+    pub const BUFFER_SIZE: usize = bindings::LINK_BUFFER_SIZE as usize;
+
+    /// Constructs a new link unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_link()`] instead.
+    ///
+    /// Both robots must use the same `name`, and one must be a
+    /// [`LinkType::Transmitter`] while the other is a [`LinkType::Receiver`].
+    pub unsafe fn new(port: u8, name: &str, link_type: LinkType) -> Result<Link, Error> {
+        assert!((1..22).contains(&port));
+        let link_type = match link_type {
+            LinkType::Receiver => bindings::link_type_e_E_LINK_RX,
+            LinkType::Transmitter => bindings::link_type_e_E_LINK_TX,
+        };
+        as_cstring(name, |cname| {
+            check_pros_err(bindings::link_init(port, cname.into_raw(), link_type) as i32)
+        })?;
+        Ok(Link { port })
+    }
+
+    /// Checks whether the radio is connected to its partner.
+    pub fn is_connected(&self) -> bool {
+        unsafe { bindings::link_connected(self.port) }
+    }
+
+    /// Gets the number of bytes available to be read from the receive buffer.
+    pub fn raw_receivable_size(&self) -> Result<usize, Error> {
+        Ok(
+            check_pros_err(unsafe { bindings::link_raw_receivable_size(self.port) } as i32)?
+                as usize,
+        )
+    }
+
+    /// Gets the number of bytes which can be written to the transmit buffer.
+    pub fn raw_transmittable_size(&self) -> Result<usize, Error> {
+        Ok(
+            check_pros_err(unsafe { bindings::link_raw_transmittable_size(self.port) } as i32)?
+                as usize,
+        )
+    }
+
+    /// Transmits raw bytes, returning the number of bytes transmitted. Fails if
+    /// `data` is longer than [`Link::BUFFER_SIZE`].
+    pub fn transmit_raw(&self, data: &[u8]) -> Result<usize, Error> {
+        let len = check_len(data.len())?;
+        Ok(check_pros_err(unsafe {
+            bindings::link_transmit_raw(self.port, data.as_ptr() as *mut _, len)
+        } as i32)? as usize)
+    }
+
+    /// Reads raw bytes from the receive buffer into `buf`, returning the number
+    /// of bytes read. Fails if `buf` is longer than [`Link::BUFFER_SIZE`].
+    pub fn receive_raw(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let len = check_len(buf.len())?;
+        Ok(check_pros_err(unsafe {
+            bindings::link_receive_raw(self.port, buf.as_mut_ptr() as *mut _, len)
+        } as i32)? as usize)
+    }
+
+    /// Transmits a packet, which PROS prefixes with a header and checksum.
+    /// Returns the number of bytes transmitted. Fails if `data` is longer than
+    /// [`Link::BUFFER_SIZE`].
+    pub fn transmit(&self, data: &[u8]) -> Result<usize, Error> {
+        let len = check_len(data.len())?;
+        Ok(check_pros_err(unsafe {
+            bindings::link_transmit(self.port, data.as_ptr() as *mut _, len)
+        } as i32)? as usize)
+    }
+
+    /// Receives a packet sent with [`Link::transmit()`] into `buf`, validating
+    /// its header and checksum. Returns the number of bytes received. Fails if
+    /// `buf` is longer than [`Link::BUFFER_SIZE`].
+    pub fn receive(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let len = check_len(buf.len())?;
+        Ok(check_pros_err(unsafe {
+            bindings::link_receive(self.port, buf.as_mut_ptr() as *mut _, len)
+        } as i32)? as usize)
+    }
+
+    /// Discards all data in the receive buffer.
+    pub fn clear_receive_buf(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::link_clear_receive_buf(self.port) } as i32)?;
+        Ok(())
+    }
+}
+
+/// A message transport built on top of a raw [`Link`].
+///
+/// Each message is sent as a frame consisting of a start byte, the length of
+/// the message, the message itself and a Fletcher-16 checksum. Received data is
+/// buffered until a complete frame has arrived, so messages are reassembled
+/// even if they are split across several reads; corrupted frames are dropped.
+pub struct MessageChannel {
+    link: Link,
+    buffer: Vec<u8>,
+}
+
+impl MessageChannel {
+    /// The maximum length of a single message in bytes.
+    pub const MAX_MESSAGE_LEN: usize = Link::BUFFER_SIZE - FRAME_OVERHEAD;
+
+    /// Creates a new message channel using the given link.
+    pub fn new(link: Link) -> Self {
+        Self {
+            link,
+            buffer: Vec::new(),
+        }
+    }
+
+    #[inline]
+    /// Gets the underlying link.
+    pub fn link(&self) -> &Link {
+        &self.link
+    }
+
+    /// Sends a message. Fails without sending anything if the message is
+    /// longer than [`MessageChannel::MAX_MESSAGE_LEN`] or there is not
+    /// currently room for it in the transmit buffer.
+    pub fn send(&self, message: &[u8]) -> Result<(), Error> {
+        if message.len() > Self::MAX_MESSAGE_LEN {
+            return Err(Error::Custom(format!(
+                "message too long: {} bytes (maximum is {})",
+                message.len(),
+                Self::MAX_MESSAGE_LEN
+            )));
+        }
+        let mut frame = Vec::with_capacity(message.len() + FRAME_OVERHEAD);
+        frame.push(FRAME_START);
+        frame.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        frame.extend_from_slice(message);
+        frame.extend_from_slice(&fletcher16(message).to_le_bytes());
+        if self.link.raw_transmittable_size()? < frame.len() {
+            return Err(Error::Custom("link transmit buffer full".to_string()));
+        }
+        self.link.transmit_raw(&frame)?;
+        Ok(())
+    }
+
+    /// Receives a complete message, if one is available. Does not block.
+    pub fn try_receive(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        let available = self.link.raw_receivable_size()?;
+        if available > 0 {
+            let start = self.buffer.len();
+            self.buffer.resize(start + available, 0);
+            let r = self.link.receive_raw(&mut self.buffer[start..]);
+            self.buffer.truncate(start + *r.as_ref().unwrap_or(&0));
+            r?;
+        }
+        Ok(self.next_frame())
+    }
+
+    /// A [`Selectable`] event which occurs when a complete message has been
+    /// received.
+    pub fn receive<'a>(&'a mut self) -> impl Selectable<Result<Vec<u8>, Error>> + 'a {
+        select_poll(MESSAGE_POLL_INTERVAL, move || {
+            self.try_receive().transpose()
+        })
+    }
+
+    fn next_frame(&mut self) -> Option<Vec<u8>> {
+        loop {
+            // Discard anything before the next start byte.
+            match self.buffer.iter().position(|&b| b == FRAME_START) {
+                Some(i) => {
+                    self.buffer.drain(..i);
+                }
+                None => {
+                    self.buffer.clear();
+                    return None;
+                }
+            }
+            if self.buffer.len() < 3 {
+                return None;
+            }
+            let len = u16::from_le_bytes([self.buffer[1], self.buffer[2]]) as usize;
+            if len > Self::MAX_MESSAGE_LEN {
+                self.buffer.remove(0);
+                continue;
+            }
+            if self.buffer.len() < len + FRAME_OVERHEAD {
+                return None;
+            }
+            let message = &self.buffer[3..3 + len];
+            let checksum = u16::from_le_bytes([self.buffer[3 + len], self.buffer[4 + len]]);
+            if fletcher16(message) == checksum {
+                let message = message.to_vec();
+                self.buffer.drain(..len + FRAME_OVERHEAD);
+                return Some(message);
+            }
+            self.buffer.remove(0);
+        }
+    }
+}
+
+#[inline]
+fn check_len(len: usize) -> Result<u16, Error> {
+    if len <= Link::BUFFER_SIZE {
+        Ok(len as u16)
+    } else {
+        Err(Error::Custom(format!(
+            "link data too long: {} bytes (maximum is {})",
+            len,
+            Link::BUFFER_SIZE
+        )))
+    }
+}
+
+fn fletcher16(data: &[u8]) -> u16 {
+    let (a, b) = data.iter().fold((0u16, 0u16), |(a, b), &x| {
+        let a = (a + x as u16) % 255;
+        (a, (b + a) % 255)
+    });
+    (b << 8) | a
+}
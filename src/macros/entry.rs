@@ -7,73 +7,115 @@
 /// #![no_std]
 /// #![no_main]
 ///
-/// use vex_rt::{entry, Robot};
+/// use vex_rt::prelude::*;
 ///
 /// struct FooBot;
 ///
-/// impl FooBot {
-///     fn initialize() -> Self {
-///         FooBot
-///     }
-/// }
-///
 /// impl Robot for FooBot {
-///     fn autonomous(&self, ctx: Context) {}
-///     fn opcontrol(&self, ctx: Context) {}
-///     fn disabled(&self, ctx: Context) {}
+///     fn initialize() -> Result<Self, Error> {
+///         Ok(FooBot)
+///     }
+///     fn autonomous(&self, ctx: Context) -> Result<(), Error> {
+///         Ok(())
+///     }
+///     fn opcontrol(&self, ctx: Context) -> Result<(), Error> {
+///         Ok(())
+///     }
+///     fn disabled(&self, ctx: Context) -> Result<(), Error> {
+///         Ok(())
+///     }
 /// }
 ///
 /// entry!(FooBot);
 /// ```
+///
+/// The name, priority and stack depth of the task spawned for each competition
+/// mode can be changed by overriding the corresponding associated constant of
+/// [`Robot`](crate::robot::Robot), such as
+/// [`Robot::AUTONOMOUS_TASK`](crate::robot::Robot::AUTONOMOUS_TASK).
 macro_rules! entry {
     ($robot_type:ty) => {
-        static ROBOT: $crate::once::Once<($robot_type, $crate::robot::ContextWrapper)> =
-            $crate::once::Once::new();
+        static ROBOT: $crate::once::Once<
+            ::core::option::Option<($robot_type, $crate::robot::ContextWrapper)>,
+        > = $crate::once::Once::new();
 
         #[no_mangle]
         unsafe extern "C" fn initialize() {
-            ROBOT.call_once(|| {
-                (
-                    <$robot_type>::initialize(),
-                    $crate::robot::ContextWrapper::new(),
-                )
-            });
+            ROBOT.call_once(
+                || match <$robot_type as $crate::robot::Robot>::initialize() {
+                    ::core::result::Result::Ok(robot) => {
+                        ::core::option::Option::Some((robot, $crate::robot::ContextWrapper::new()))
+                    }
+                    ::core::result::Result::Err(err) => {
+                        $crate::robot::report_error(
+                            "initialize",
+                            &$crate::rtos::Task::current().name(),
+                            &err,
+                        );
+                        ::core::option::Option::None
+                    }
+                },
+            );
         }
 
         #[no_mangle]
         extern "C" fn opcontrol() {
-            let (robot, wrapper) = ROBOT.get().unwrap();
-            $crate::rtos::Task::spawn_ext(
-                "opcontrol",
-                $crate::rtos::Task::DEFAULT_PRIORITY,
-                $crate::rtos::Task::DEFAULT_STACK_DEPTH,
-                move || $crate::robot::Robot::opcontrol(robot, wrapper.replace()),
-            )
-            .unwrap();
+            if let ::core::option::Option::Some(::core::option::Option::Some((robot, wrapper))) =
+                ROBOT.get()
+            {
+                $crate::robot::spawn_mode(
+                    "opcontrol",
+                    <$robot_type as $crate::robot::Robot>::OPCONTROL_TASK,
+                    <$robot_type as $crate::robot::Robot>::MODE_TRANSITION_TIMEOUT,
+                    wrapper,
+                    move |ctx| $crate::robot::Robot::opcontrol(robot, ctx),
+                );
+            }
         }
 
         #[no_mangle]
         extern "C" fn autonomous() {
-            let (robot, wrapper) = ROBOT.get().unwrap();
-            $crate::rtos::Task::spawn_ext(
-                "autonomous",
-                $crate::rtos::Task::DEFAULT_PRIORITY,
-                $crate::rtos::Task::DEFAULT_STACK_DEPTH,
-                move || $crate::robot::Robot::autonomous(robot, wrapper.replace()),
-            )
-            .unwrap();
+            if let ::core::option::Option::Some(::core::option::Option::Some((robot, wrapper))) =
+                ROBOT.get()
+            {
+                $crate::robot::spawn_mode(
+                    "autonomous",
+                    <$robot_type as $crate::robot::Robot>::AUTONOMOUS_TASK,
+                    <$robot_type as $crate::robot::Robot>::MODE_TRANSITION_TIMEOUT,
+                    wrapper,
+                    move |ctx| $crate::robot::Robot::autonomous(robot, ctx),
+                );
+            }
         }
 
         #[no_mangle]
         extern "C" fn disabled() {
-            let (robot, wrapper) = ROBOT.get().unwrap();
-            $crate::rtos::Task::spawn_ext(
-                "disabled",
-                $crate::rtos::Task::DEFAULT_PRIORITY,
-                $crate::rtos::Task::DEFAULT_STACK_DEPTH,
-                move || $crate::robot::Robot::disabled(robot, wrapper.replace()),
-            )
-            .unwrap();
+            if let ::core::option::Option::Some(::core::option::Option::Some((robot, wrapper))) =
+                ROBOT.get()
+            {
+                $crate::robot::spawn_mode(
+                    "disabled",
+                    <$robot_type as $crate::robot::Robot>::DISABLED_TASK,
+                    <$robot_type as $crate::robot::Robot>::MODE_TRANSITION_TIMEOUT,
+                    wrapper,
+                    move |ctx| $crate::robot::Robot::disabled(robot, ctx),
+                );
+            }
+        }
+
+        #[no_mangle]
+        extern "C" fn competition_initialize() {
+            if let ::core::option::Option::Some(::core::option::Option::Some((robot, wrapper))) =
+                ROBOT.get()
+            {
+                $crate::robot::spawn_mode(
+                    "competition_initialize",
+                    <$robot_type as $crate::robot::Robot>::COMPETITION_INITIALIZE_TASK,
+                    <$robot_type as $crate::robot::Robot>::MODE_TRANSITION_TIMEOUT,
+                    wrapper,
+                    move |ctx| $crate::robot::Robot::competition_initialize(robot, ctx),
+                );
+            }
         }
     };
 }
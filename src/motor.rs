@@ -1,7 +1,18 @@
 //! Bindings.
 
-use crate::bindings;
+use alloc::{format, string::ToString, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::BitOr,
+};
 
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, from_errno, Error, PROS_ERR},
+    units::{round, Angle, AngularVelocity, Voltage},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 /// An enum which represents possible gear cartridges for a motor.
 pub enum Gearset {
     /// Blue 6:1 Gearset (600RPM)
@@ -20,6 +31,365 @@ impl Gearset {
             Gearset::ThirtySixToOne => bindings::motor_gearset_e::E_MOTOR_GEARSET_36,
         }
     }
+
+    fn from_motor_gearset_e_t(gearset: bindings::motor_gearset_e_t) -> Result<Self, Error> {
+        match gearset {
+            bindings::motor_gearset_e::E_MOTOR_GEARSET_06 => Ok(Gearset::SixToOne),
+            bindings::motor_gearset_e::E_MOTOR_GEARSET_18 => Ok(Gearset::EighteenToOne),
+            bindings::motor_gearset_e::E_MOTOR_GEARSET_36 => Ok(Gearset::ThirtySixToOne),
+            bindings::motor_gearset_e::E_MOTOR_GEARSET_INVALID => Err(from_errno()),
+        }
+    }
+
+    /// Gets the maximum velocity of a motor with this gearset.
+    pub fn max_velocity(&self) -> AngularVelocity {
+        match self {
+            Gearset::SixToOne => AngularVelocity::from_rpm(600.0),
+            Gearset::EighteenToOne => AngularVelocity::from_rpm(200.0),
+            Gearset::ThirtySixToOne => AngularVelocity::from_rpm(100.0),
+        }
+    }
+
+    /// Gets the number of encoder counts per rotation of a motor's output
+    /// shaft with this gearset.
+    pub fn counts_per_rotation(&self) -> f64 {
+        match self {
+            Gearset::SixToOne => 300.0,
+            Gearset::EighteenToOne => 900.0,
+            Gearset::ThirtySixToOne => 1800.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// An enum which represents possible brake behaviours for a motor.
+pub enum BrakeMode {
+    /// Motor coasts when stopped.
+    Coast,
+    /// Motor brakes when stopped.
+    Brake,
+    /// Motor actively holds its position when stopped.
+    Hold,
+}
+
+impl BrakeMode {
+    fn to_motor_brake_mode_e_t(&self) -> bindings::motor_brake_mode_e_t {
+        match self {
+            BrakeMode::Coast => bindings::motor_brake_mode_e::E_MOTOR_BRAKE_COAST,
+            BrakeMode::Brake => bindings::motor_brake_mode_e::E_MOTOR_BRAKE_BRAKE,
+            BrakeMode::Hold => bindings::motor_brake_mode_e::E_MOTOR_BRAKE_HOLD,
+        }
+    }
+
+    fn from_motor_brake_mode_e_t(mode: bindings::motor_brake_mode_e_t) -> Result<Self, Error> {
+        match mode {
+            bindings::motor_brake_mode_e::E_MOTOR_BRAKE_COAST => Ok(BrakeMode::Coast),
+            bindings::motor_brake_mode_e::E_MOTOR_BRAKE_BRAKE => Ok(BrakeMode::Brake),
+            bindings::motor_brake_mode_e::E_MOTOR_BRAKE_HOLD => Ok(BrakeMode::Hold),
+            bindings::motor_brake_mode_e::E_MOTOR_BRAKE_INVALID => Err(from_errno()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// An enum which represents possible units for a motor's encoder.
+pub enum EncoderUnits {
+    /// Position is reported in degrees.
+    Degrees,
+    /// Position is reported in full rotations.
+    Rotations,
+    /// Position is reported in raw encoder ticks.
+    Counts,
+}
+
+impl EncoderUnits {
+    fn to_motor_encoder_units_e_t(&self) -> bindings::motor_encoder_units_e_t {
+        match self {
+            EncoderUnits::Degrees => bindings::motor_encoder_units_e::E_MOTOR_ENCODER_DEGREES,
+            EncoderUnits::Rotations => bindings::motor_encoder_units_e::E_MOTOR_ENCODER_ROTATIONS,
+            EncoderUnits::Counts => bindings::motor_encoder_units_e::E_MOTOR_ENCODER_COUNTS,
+        }
+    }
+
+    fn from_motor_encoder_units_e_t(
+        units: bindings::motor_encoder_units_e_t,
+    ) -> Result<Self, Error> {
+        match units {
+            bindings::motor_encoder_units_e::E_MOTOR_ENCODER_DEGREES => Ok(EncoderUnits::Degrees),
+            bindings::motor_encoder_units_e::E_MOTOR_ENCODER_ROTATIONS => {
+                Ok(EncoderUnits::Rotations)
+            }
+            bindings::motor_encoder_units_e::E_MOTOR_ENCODER_COUNTS => Ok(EncoderUnits::Counts),
+            bindings::motor_encoder_units_e::E_MOTOR_ENCODER_INVALID => Err(from_errno()),
+        }
+    }
+
+    /// Converts an angle to a position in these units, for a motor with the
+    /// given gearset.
+    pub fn angle_to_position(&self, angle: Angle, gearset: Gearset) -> f64 {
+        match self {
+            EncoderUnits::Degrees => angle.as_degrees(),
+            EncoderUnits::Rotations => angle.as_rotations(),
+            EncoderUnits::Counts => angle.as_rotations() * gearset.counts_per_rotation(),
+        }
+    }
+
+    /// Converts a position in these units to an angle, for a motor with the
+    /// given gearset.
+    pub fn position_to_angle(&self, position: f64, gearset: Gearset) -> Angle {
+        match self {
+            EncoderUnits::Degrees => Angle::from_degrees(position),
+            EncoderUnits::Rotations => Angle::from_rotations(position),
+            EncoderUnits::Counts => Angle::from_rotations(position / gearset.counts_per_rotation()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// A builder for the full configuration of a motor, applied when a
+/// [`SmartPort`](crate::smart_port::SmartPort) is converted into a [`Motor`].
+///
+/// # Examples
+///
+/// ```
+/// use vex_rt::motor::{BrakeMode, EncoderUnits, Gearset, MotorConfig};
+/// let config = MotorConfig::new(Gearset::EighteenToOne)
+///     .reverse(true)
+///     .brake_mode(BrakeMode::Hold)
+///     .encoder_units(EncoderUnits::Rotations)
+///     .current_limit(2000);
+/// ```
+pub struct MotorConfig {
+    gearset: Gearset,
+    reverse: bool,
+    brake_mode: Option<BrakeMode>,
+    encoder_units: Option<EncoderUnits>,
+    current_limit: Option<i32>,
+    voltage_limit: Option<i32>,
+}
+
+impl MotorConfig {
+    /// Creates a new configuration with the given gearset, which is not
+    /// reversed and leaves every other setting at its PROS default.
+    pub fn new(gearset: Gearset) -> Self {
+        MotorConfig {
+            gearset,
+            reverse: false,
+            brake_mode: None,
+            encoder_units: None,
+            current_limit: None,
+            voltage_limit: None,
+        }
+    }
+
+    /// Sets whether the motor is reversed.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Sets the brake mode of the motor.
+    pub fn brake_mode(mut self, mode: BrakeMode) -> Self {
+        self.brake_mode = Some(mode);
+        self
+    }
+
+    /// Sets the encoder units of the motor.
+    pub fn encoder_units(mut self, units: EncoderUnits) -> Self {
+        self.encoder_units = Some(units);
+        self
+    }
+
+    /// Sets the current limit of the motor in mA.
+    pub fn current_limit(mut self, limit: i32) -> Self {
+        self.current_limit = Some(limit);
+        self
+    }
+
+    /// Sets the voltage limit of the motor in millivolts.
+    pub fn voltage_limit(mut self, limit: i32) -> Self {
+        self.voltage_limit = Some(limit);
+        self
+    }
+}
+
+impl From<Gearset> for MotorConfig {
+    #[inline]
+    fn from(gearset: Gearset) -> Self {
+        Self::new(gearset)
+    }
+}
+
+impl From<(Gearset, bool)> for MotorConfig {
+    #[inline]
+    fn from((gearset, reverse): (Gearset, bool)) -> Self {
+        Self::new(gearset).reverse(reverse)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+/// A set of fault conditions reported by a motor.
+pub struct MotorFaults(u32);
+
+impl MotorFaults {
+    /// The motor is over its temperature limit.
+    pub const OVER_TEMP: Self = Self(bindings::motor_fault_e::E_MOTOR_FAULT_MOTOR_OVER_TEMP as u32);
+    /// The motor's H-bridge has encountered a fault.
+    pub const DRIVER_FAULT: Self = Self(bindings::motor_fault_e::E_MOTOR_FAULT_DRIVER_FAULT as u32);
+    /// The motor is over its current limit.
+    pub const OVER_CURRENT: Self = Self(bindings::motor_fault_e::E_MOTOR_FAULT_OVER_CURRENT as u32);
+    /// The motor's H-bridge is over its current limit.
+    pub const DRIVER_OVER_CURRENT: Self =
+        Self(bindings::motor_fault_e::E_MOTOR_FAULT_DRV_OVER_CURRENT as u32);
+
+    const NAMES: [(Self, &'static str); 4] = [
+        (Self::OVER_TEMP, "OVER_TEMP"),
+        (Self::DRIVER_FAULT, "DRIVER_FAULT"),
+        (Self::OVER_CURRENT, "OVER_CURRENT"),
+        (Self::DRIVER_OVER_CURRENT, "DRIVER_OVER_CURRENT"),
+    ];
+
+    #[inline]
+    /// Creates a set of faults from the raw PROS bitfield, discarding unknown
+    /// bits.
+    pub fn from_bits_truncate(bits: u32) -> Self {
+        Self(bits & Self::NAMES.iter().fold(0, |acc, (f, _)| acc | f.0))
+    }
+
+    #[inline]
+    /// Gets the raw PROS bitfield.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    /// Checks whether no faults are present.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    /// Checks whether all of the faults in `other` are present.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for MotorFaults {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Debug for MotorFaults {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_flags(
+            f,
+            "MotorFaults",
+            self.0,
+            Self::NAMES.iter().map(|(v, n)| (v.0, *n)),
+        )
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+/// A set of status flags reported by a motor.
+pub struct MotorFlags(u32);
+
+impl MotorFlags {
+    /// The motor cannot currently communicate with the brain.
+    pub const BUSY: Self = Self(bindings::motor_flag_e::E_MOTOR_FLAGS_BUSY as u32);
+    /// The motor is stopped.
+    pub const ZERO_VELOCITY: Self =
+        Self(bindings::motor_flag_e::E_MOTOR_FLAGS_ZERO_VELOCITY as u32);
+    /// The motor is at its zero position.
+    pub const ZERO_POSITION: Self =
+        Self(bindings::motor_flag_e::E_MOTOR_FLAGS_ZERO_POSITION as u32);
+
+    const NAMES: [(Self, &'static str); 3] = [
+        (Self::BUSY, "BUSY"),
+        (Self::ZERO_VELOCITY, "ZERO_VELOCITY"),
+        (Self::ZERO_POSITION, "ZERO_POSITION"),
+    ];
+
+    #[inline]
+    /// Creates a set of flags from the raw PROS bitfield, discarding unknown
+    /// bits.
+    pub fn from_bits_truncate(bits: u32) -> Self {
+        Self(bits & Self::NAMES.iter().fold(0, |acc, (f, _)| acc | f.0))
+    }
+
+    #[inline]
+    /// Gets the raw PROS bitfield.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    /// Checks whether no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    /// Checks whether all of the flags in `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for MotorFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Debug for MotorFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_flags(
+            f,
+            "MotorFlags",
+            self.0,
+            Self::NAMES.iter().map(|(v, n)| (v.0, *n)),
+        )
+    }
+}
+
+fn fmt_flags(
+    f: &mut Formatter<'_>,
+    name: &str,
+    bits: u32,
+    names: impl Iterator<Item = (u32, &'static str)>,
+) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    let mut first = true;
+    for (_, n) in names.filter(|(v, _)| bits & v == *v) {
+        if !first {
+            f.write_str(" | ")?;
+        }
+        f.write_str(n)?;
+        first = false;
+    }
+    if first {
+        f.write_str("empty")?;
+    }
+    f.write_str(")")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// A snapshot of the fault conditions and status flags of a motor, as returned
+/// by [`Motor::status()`].
+pub struct MotorStatus {
+    /// The fault conditions currently present.
+    pub faults: MotorFaults,
+    /// The status flags currently set.
+    pub flags: MotorFlags,
 }
 
 /// A struct which represents a V5 smart port configured as a motor.
@@ -37,6 +407,32 @@ impl Motor {
         Motor { port }
     }
 
+    /// Constructs a new motor unsafely from a full configuration. You probably
+    /// want to use [`crate::smart_port::SmartPort::as_motor()`]
+    /// instead.
+    pub unsafe fn from_config(port: u8, config: MotorConfig) -> Result<Motor, Error> {
+        assert!((1..22).contains(&port));
+        check_pros_err(bindings::motor_set_gearing(
+            port,
+            config.gearset.to_motor_gearset_e_t(),
+        ))?;
+        check_pros_err(bindings::motor_set_reversed(port, config.reverse))?;
+        let motor = Motor { port };
+        if let Some(mode) = config.brake_mode {
+            motor.set_brake_mode(mode)?;
+        }
+        if let Some(units) = config.encoder_units {
+            motor.set_encoder_units(units)?;
+        }
+        if let Some(limit) = config.current_limit {
+            motor.set_current_limit(limit)?;
+        }
+        if let Some(limit) = config.voltage_limit {
+            motor.set_voltage_limit(limit)?;
+        }
+        Ok(motor)
+    }
+
     /// Sets the voltage for the motor on the range -127 to 127. Useful when
     /// mapping controller output to motor control.
     pub fn move_i8(&self, voltage: i8) {
@@ -45,7 +441,8 @@ impl Motor {
 
     /// Sets the target position for the motor, relative to either the motor's
     /// position when it was initialized or the motor's position during the most
-    /// recent call to `Motor::tare_position(&self)`.
+    /// recent call to [`Motor::tare_position()`]. The position is in the
+    /// motor's encoder units.
     pub fn move_absolute(&self, position: f64, velocity: i32) {
         unsafe { bindings::motor_move_absolute(self.port, position, velocity) };
     }
@@ -87,4 +484,404 @@ impl Motor {
     pub fn get_target_velocity(&self) -> i32 {
         unsafe { bindings::motor_get_target_velocity(self.port) }
     }
+
+    /// Gets the actual velocity of the motor in RPM.
+    pub fn get_actual_velocity(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_actual_velocity(self.port) })
+    }
+
+    /// Gets the current drawn by the motor in mA.
+    pub fn get_current_draw(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::motor_get_current_draw(self.port) })
+    }
+
+    /// Gets the direction of movement for the motor: 1 for moving in the
+    /// positive direction and -1 for moving in the negative direction.
+    pub fn get_direction(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::motor_get_direction(self.port) })
+    }
+
+    /// Gets the efficiency of the motor in percent.
+    ///
+    /// An efficiency of 100% means that the motor is moving electrically while
+    /// drawing no electrical power, and an efficiency of 0% means that the
+    /// motor is drawing power but not moving.
+    pub fn get_efficiency(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_efficiency(self.port) })
+    }
+
+    /// Gets the absolute position of the motor in its encoder units, relative
+    /// to the zero position set by [`Motor::tare_position()`] or
+    /// [`Motor::set_zero_position()`].
+    pub fn get_position(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_position(self.port) })
+    }
+
+    /// Sets the zero position of the motor to its current position.
+    pub fn tare_position(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::motor_tare_position(self.port) })?;
+        Ok(())
+    }
+
+    /// Sets the zero position of the motor to `position`, in the motor's
+    /// encoder units. Future absolute positions are measured relative to it.
+    pub fn set_zero_position(&self, position: f64) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::motor_set_zero_position(self.port, position) })?;
+        Ok(())
+    }
+
+    /// Gets the power drawn by the motor in Watts.
+    pub fn get_power(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_power(self.port) })
+    }
+
+    /// Gets the temperature of the motor in degrees Celsius.
+    pub fn get_temperature(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_temperature(self.port) })
+    }
+
+    /// Gets the torque generated by the motor in Newton Meters (Nm).
+    pub fn get_torque(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::motor_get_torque(self.port) })
+    }
+
+    /// Gets the voltage delivered to the motor in millivolts.
+    pub fn get_voltage(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::motor_get_voltage(self.port) })
+    }
+
+    /// Sets the brake mode of the motor.
+    pub fn set_brake_mode(&self, mode: BrakeMode) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::motor_set_brake_mode(self.port, mode.to_motor_brake_mode_e_t())
+        })?;
+        Ok(())
+    }
+
+    /// Gets the brake mode of the motor.
+    pub fn get_brake_mode(&self) -> Result<BrakeMode, Error> {
+        BrakeMode::from_motor_brake_mode_e_t(unsafe { bindings::motor_get_brake_mode(self.port) })
+    }
+
+    /// Sets the units used by the motor's encoder, which affects the position
+    /// values used by [`Motor::move_absolute()`], [`Motor::move_relative()`]
+    /// and [`Motor::get_position()`].
+    pub fn set_encoder_units(&self, units: EncoderUnits) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::motor_set_encoder_units(self.port, units.to_motor_encoder_units_e_t())
+        })?;
+        Ok(())
+    }
+
+    /// Gets the units used by the motor's encoder.
+    pub fn get_encoder_units(&self) -> Result<EncoderUnits, Error> {
+        EncoderUnits::from_motor_encoder_units_e_t(unsafe {
+            bindings::motor_get_encoder_units(self.port)
+        })
+    }
+
+    /// Sets the current limit of the motor in mA.
+    pub fn set_current_limit(&self, limit: i32) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::motor_set_current_limit(self.port, limit) })?;
+        Ok(())
+    }
+
+    /// Gets the current limit of the motor in mA. The default is 2500 mA.
+    pub fn get_current_limit(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::motor_get_current_limit(self.port) })
+    }
+
+    /// Sets the voltage limit of the motor in millivolts.
+    pub fn set_voltage_limit(&self, limit: i32) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::motor_set_voltage_limit(self.port, limit) })?;
+        Ok(())
+    }
+
+    /// Gets the voltage limit of the motor in millivolts, where 0 means that
+    /// there is no limit.
+    pub fn get_voltage_limit(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::motor_get_voltage_limit(self.port) })
+    }
+
+    /// Gets the gearset of the motor.
+    pub fn get_gearing(&self) -> Result<Gearset, Error> {
+        Gearset::from_motor_gearset_e_t(unsafe { bindings::motor_get_gearing(self.port) })
+    }
+
+    /// Sets the voltage for the motor. Fails if the voltage is outside of the
+    /// range -12 V to 12 V; see [`Voltage::clamped()`].
+    pub fn set_voltage(&self, voltage: Voltage) -> Result<(), Error> {
+        if voltage.clamped() != voltage {
+            return Err(Error::Custom(format!(
+                "voltage out of range: {} (maximum is {})",
+                voltage,
+                Voltage::MAX
+            )));
+        }
+        check_pros_err(unsafe {
+            bindings::motor_move_voltage(self.port, voltage.as_millivolts())
+        })?;
+        Ok(())
+    }
+
+    /// Sets the velocity for the motor, which is held with PID. Fails if the
+    /// velocity is faster than the maximum for the motor's gearset; see
+    /// [`AngularVelocity::clamped()`] and [`Gearset::max_velocity()`].
+    pub fn set_velocity(&self, velocity: AngularVelocity) -> Result<(), Error> {
+        let velocity = self.check_velocity(velocity)?;
+        check_pros_err(unsafe { bindings::motor_move_velocity(self.port, velocity) })?;
+        Ok(())
+    }
+
+    /// Sets the target angle for the motor, relative to its zero position (see
+    /// [`Motor::move_absolute()`]), converted to the motor's encoder units.
+    /// Fails if the velocity is faster than the maximum for the motor's
+    /// gearset.
+    pub fn move_to(&self, angle: Angle, velocity: AngularVelocity) -> Result<(), Error> {
+        let velocity = self.check_velocity(velocity)?;
+        let position = self.angle_to_position(angle)?;
+        check_pros_err(unsafe { bindings::motor_move_absolute(self.port, position, velocity) })?;
+        Ok(())
+    }
+
+    /// Sets the target angle for the motor, relative to its current position
+    /// (see [`Motor::move_relative()`]), converted to the motor's encoder
+    /// units. Fails if the velocity is faster than the maximum for the motor's
+    /// gearset.
+    pub fn move_by(&self, angle: Angle, velocity: AngularVelocity) -> Result<(), Error> {
+        let velocity = self.check_velocity(velocity)?;
+        let position = self.angle_to_position(angle)?;
+        check_pros_err(unsafe { bindings::motor_move_relative(self.port, position, velocity) })?;
+        Ok(())
+    }
+
+    /// Gets the angle of the motor relative to its zero position.
+    pub fn get_angle(&self) -> Result<Angle, Error> {
+        let position = self.get_position()?;
+        Ok(self
+            .get_encoder_units()?
+            .position_to_angle(position, self.get_gearing()?))
+    }
+
+    /// Gets the actual angular velocity of the motor.
+    pub fn get_angular_velocity(&self) -> Result<AngularVelocity, Error> {
+        Ok(AngularVelocity::from_rpm(self.get_actual_velocity()?))
+    }
+
+    fn check_velocity(&self, velocity: AngularVelocity) -> Result<i32, Error> {
+        let gearset = self.get_gearing()?;
+        let max = gearset.max_velocity();
+        if velocity.clamped(max) != velocity {
+            return Err(Error::Custom(format!(
+                "velocity out of range for {:?} gearset: {} (maximum is {})",
+                gearset, velocity, max
+            )));
+        }
+        Ok(round(velocity.as_rpm()) as i32)
+    }
+
+    fn angle_to_position(&self, angle: Angle) -> Result<f64, Error> {
+        Ok(self
+            .get_encoder_units()?
+            .angle_to_position(angle, self.get_gearing()?))
+    }
+
+    /// Sets whether the motor is reversed.
+    pub fn set_reversed(&self, reverse: bool) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::motor_set_reversed(self.port, reverse) })?;
+        Ok(())
+    }
+
+    /// Checks whether the motor is reversed.
+    pub fn is_reversed(&self) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe { bindings::motor_is_reversed(self.port) })? != 0)
+    }
+
+    /// Gets the fault conditions and status flags of the motor.
+    pub fn status(&self) -> Result<MotorStatus, Error> {
+        let faults = unsafe { bindings::motor_get_faults(self.port) };
+        if faults == PROS_ERR as u32 {
+            return Err(from_errno());
+        }
+        let flags = unsafe { bindings::motor_get_flags(self.port) };
+        if flags == PROS_ERR as u32 {
+            return Err(from_errno());
+        }
+        Ok(MotorStatus {
+            faults: MotorFaults::from_bits_truncate(faults),
+            flags: MotorFlags::from_bits_truncate(flags),
+        })
+    }
+}
+
+/// A struct which represents several motors which are driven as one unit, such
+/// as one side of a drivetrain.
+///
+/// Every command is forwarded to each motor in the group. Each motor keeps its
+/// own reversal setting, so motors which are mounted in opposite directions can
+/// be reversed individually (e.g., with [`MotorConfig::reverse()`] or
+/// [`Motor::set_reversed()`]) and still be commanded together.
+pub struct MotorGroup {
+    motors: Vec<Motor>,
+}
+
+impl MotorGroup {
+    /// Creates a new motor group from a non-empty set of motors. Fails if
+    /// `motors` is empty.
+    pub fn new(motors: Vec<Motor>) -> Result<Self, Error> {
+        if motors.is_empty() {
+            return Err(Error::Custom(
+                "cannot construct an empty motor group".to_string(),
+            ));
+        }
+        Ok(Self { motors })
+    }
+
+    #[inline]
+    /// Gets the motors in the group.
+    pub fn motors(&self) -> &[Motor] {
+        &self.motors
+    }
+
+    #[inline]
+    /// Consumes the group, returning the motors in it.
+    pub fn into_inner(self) -> Vec<Motor> {
+        self.motors
+    }
+
+    /// Sets the voltage for every motor on the range -127 to 127. See
+    /// [`Motor::move_i8()`].
+    pub fn move_i8(&self, voltage: i8) {
+        self.motors.iter().for_each(|m| m.move_i8(voltage));
+    }
+
+    /// Sets the target position for every motor. See
+    /// [`Motor::move_absolute()`].
+    pub fn move_absolute(&self, position: f64, velocity: i32) {
+        self.motors
+            .iter()
+            .for_each(|m| m.move_absolute(position, velocity));
+    }
+
+    /// Sets the target position for every motor, relative to its current
+    /// position. See [`Motor::move_relative()`].
+    pub fn move_relative(&self, position: f64, velocity: i32) {
+        self.motors
+            .iter()
+            .for_each(|m| m.move_relative(position, velocity));
+    }
+
+    /// Sets the velocity for every motor. See [`Motor::move_velocity()`].
+    pub fn move_velocity(&self, velocity: i32) {
+        self.motors.iter().for_each(|m| m.move_velocity(velocity));
+    }
+
+    /// Sets the voltage for every motor from -12000 mV to 12000 mV. See
+    /// [`Motor::move_voltage()`].
+    pub fn move_voltage(&self, voltage: i32) {
+        self.motors.iter().for_each(|m| m.move_voltage(voltage));
+    }
+
+    /// Changes the output velocity for a profiled movement of every motor. See
+    /// [`Motor::modify_profiled_velocity()`].
+    pub fn modify_profiled_velocity(&self, velocity: i32) {
+        self.motors
+            .iter()
+            .for_each(|m| m.modify_profiled_velocity(velocity));
+    }
+
+    /// Sets the brake mode of every motor. Every motor is updated even if some
+    /// fail; the first error is returned.
+    pub fn set_brake_mode(&self, mode: BrakeMode) -> Result<(), Error> {
+        self.for_each_checked(|m| m.set_brake_mode(mode))
+    }
+
+    /// Sets the encoder units of every motor. Every motor is updated even if
+    /// some fail; the first error is returned.
+    pub fn set_encoder_units(&self, units: EncoderUnits) -> Result<(), Error> {
+        self.for_each_checked(|m| m.set_encoder_units(units))
+    }
+
+    /// Sets the current limit of every motor in mA. Every motor is updated even
+    /// if some fail; the first error is returned.
+    pub fn set_current_limit(&self, limit: i32) -> Result<(), Error> {
+        self.for_each_checked(|m| m.set_current_limit(limit))
+    }
+
+    /// Sets the voltage limit of every motor in millivolts. Every motor is
+    /// updated even if some fail; the first error is returned.
+    pub fn set_voltage_limit(&self, limit: i32) -> Result<(), Error> {
+        self.for_each_checked(|m| m.set_voltage_limit(limit))
+    }
+
+    /// Sets the zero position of every motor to its current position. Every
+    /// motor is updated even if some fail; the first error is returned.
+    pub fn tare_position(&self) -> Result<(), Error> {
+        self.for_each_checked(Motor::tare_position)
+    }
+
+    /// Sets the zero position of every motor to `position`, in their encoder
+    /// units. Every motor is updated even if some fail; the first error is
+    /// returned.
+    pub fn set_zero_position(&self, position: f64) -> Result<(), Error> {
+        self.for_each_checked(|m| m.set_zero_position(position))
+    }
+
+    /// Gets the mean position of the motors in their encoder units.
+    pub fn get_position(&self) -> Result<f64, Error> {
+        self.mean(Motor::get_position)
+    }
+
+    /// Gets the mean actual velocity of the motors in RPM.
+    pub fn get_actual_velocity(&self) -> Result<f64, Error> {
+        self.mean(Motor::get_actual_velocity)
+    }
+
+    /// Gets the highest temperature of the motors in degrees Celsius.
+    pub fn get_temperature(&self) -> Result<f64, Error> {
+        self.motors.iter().try_fold(
+            f64::NEG_INFINITY,
+            |acc, m| Ok(acc.max(m.get_temperature()?)),
+        )
+    }
+
+    /// Gets the total current drawn by the motors in mA.
+    pub fn get_current_draw(&self) -> Result<i32, Error> {
+        self.motors
+            .iter()
+            .try_fold(0, |acc, m| Ok(acc + m.get_current_draw()?))
+    }
+
+    /// Gets the total power drawn by the motors in Watts.
+    pub fn get_power(&self) -> Result<f64, Error> {
+        self.motors
+            .iter()
+            .try_fold(0.0, |acc, m| Ok(acc + m.get_power()?))
+    }
+
+    /// Gets the total torque generated by the motors in Newton Meters (Nm).
+    pub fn get_torque(&self) -> Result<f64, Error> {
+        self.motors
+            .iter()
+            .try_fold(0.0, |acc, m| Ok(acc + m.get_torque()?))
+    }
+
+    fn mean(&self, f: impl Fn(&Motor) -> Result<f64, Error>) -> Result<f64, Error> {
+        let sum = self
+            .motors
+            .iter()
+            .try_fold(0.0, |acc, m| Ok::<_, Error>(acc + f(m)?))?;
+        Ok(sum / self.motors.len() as f64)
+    }
+
+    fn for_each_checked(&self, f: impl Fn(&Motor) -> Result<(), Error>) -> Result<(), Error> {
+        let mut result = Ok(());
+        for motor in self.motors.iter() {
+            let r = f(motor);
+            if result.is_ok() {
+                result = r;
+            }
+        }
+        result
+    }
 }
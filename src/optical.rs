@@ -0,0 +1,148 @@
+//! Optical sensor API.
+
+use crate::{
+    bindings,
+    error::{check_pros_err, check_pros_err_f, from_errno, Error, PROS_ERR, PROS_ERR_F},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents a processed color reading from an optical sensor.
+pub struct OpticalRgb {
+    /// The red component.
+    pub red: f64,
+    /// The green component.
+    pub green: f64,
+    /// The blue component.
+    pub blue: f64,
+    /// The overall brightness.
+    pub brightness: f64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents an unprocessed color reading from an optical sensor.
+pub struct OpticalRaw {
+    /// The clear (unfiltered) channel.
+    pub clear: u32,
+    /// The red channel.
+    pub red: u32,
+    /// The green channel.
+    pub green: u32,
+    /// The blue channel.
+    pub blue: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents a gesture detected by an optical sensor.
+pub enum Gesture {
+    /// An upward swipe.
+    Up,
+    /// A downward swipe.
+    Down,
+    /// A swipe to the right.
+    Right,
+    /// A swipe to the left.
+    Left,
+}
+
+/// A struct which represents a V5 smart port configured as an optical sensor.
+pub struct OpticalSensor {
+    port: u8,
+}
+
+impl OpticalSensor {
+    /// Constructs a new optical sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_optical_sensor()`] instead.
+    pub unsafe fn new(port: u8) -> OpticalSensor {
+        assert!((1..22).contains(&port));
+        OpticalSensor { port }
+    }
+
+    /// Gets the hue of the detected color in degrees, on the range 0 to 359.
+    pub fn get_hue(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::optical_get_hue(self.port) })
+    }
+
+    /// Gets the saturation of the detected color, on the range 0 to 1.
+    pub fn get_saturation(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::optical_get_saturation(self.port) })
+    }
+
+    /// Gets the brightness of the detected color, on the range 0 to 1.
+    pub fn get_brightness(&self) -> Result<f64, Error> {
+        check_pros_err_f(unsafe { bindings::optical_get_brightness(self.port) })
+    }
+
+    /// Gets the proximity of the detected object, on the range 0 to 255, where
+    /// larger values are closer.
+    pub fn get_proximity(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::optical_get_proximity(self.port) })
+    }
+
+    /// Sets the brightness of the sensor's LED in percent, on the range 0 to
+    /// 100.
+    pub fn set_led_pwm(&self, value: u8) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::optical_set_led_pwm(self.port, value) })?;
+        Ok(())
+    }
+
+    /// Gets the brightness of the sensor's LED in percent.
+    pub fn get_led_pwm(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::optical_get_led_pwm(self.port) })
+    }
+
+    /// Gets the processed color reading of the sensor.
+    pub fn get_rgb(&self) -> Result<OpticalRgb, Error> {
+        let rgb = unsafe { bindings::optical_get_rgb(self.port) };
+        if rgb.red == PROS_ERR_F {
+            Err(from_errno())
+        } else {
+            Ok(OpticalRgb {
+                red: rgb.red,
+                green: rgb.green,
+                blue: rgb.blue,
+                brightness: rgb.brightness,
+            })
+        }
+    }
+
+    /// Gets the unprocessed color reading of the sensor.
+    pub fn get_raw(&self) -> Result<OpticalRaw, Error> {
+        let raw = unsafe { bindings::optical_get_raw(self.port) };
+        if raw.clear == PROS_ERR as u32 {
+            Err(from_errno())
+        } else {
+            Ok(OpticalRaw {
+                clear: raw.clear,
+                red: raw.red,
+                green: raw.green,
+                blue: raw.blue,
+            })
+        }
+    }
+
+    /// Gets the most recent gesture detected by the sensor, if any. Gesture
+    /// detection must first be enabled with
+    /// [`OpticalSensor::enable_gesture()`].
+    pub fn get_gesture(&self) -> Result<Option<Gesture>, Error> {
+        match unsafe { bindings::optical_get_gesture(self.port) } {
+            bindings::optical_direction_e_UP => Ok(Some(Gesture::Up)),
+            bindings::optical_direction_e_DOWN => Ok(Some(Gesture::Down)),
+            bindings::optical_direction_e_RIGHT => Ok(Some(Gesture::Right)),
+            bindings::optical_direction_e_LEFT => Ok(Some(Gesture::Left)),
+            bindings::optical_direction_e_ERROR => Err(from_errno()),
+            _ => Ok(None),
+        }
+    }
+
+    /// Enables gesture detection.
+    pub fn enable_gesture(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::optical_enable_gesture(self.port) })?;
+        Ok(())
+    }
+
+    /// Disables gesture detection.
+    pub fn disable_gesture(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::optical_disable_gesture(self.port) })?;
+        Ok(())
+    }
+}
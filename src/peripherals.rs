@@ -1,9 +1,17 @@
 //! Peripherals.
 
-use crate::smart_port::SmartPort;
+use crate::{
+    adi_port::AdiPort,
+    controller::{Controller, ControllerId},
+    smart_port::SmartPort,
+};
 
 /// A struct which represents all the peripherals on the V5 brain.
 pub struct Peripherals {
+    /// The master controller.
+    pub master_controller: Controller,
+    /// The partner controller.
+    pub partner_controller: Controller,
     /// Smart Port 1
     pub port01: SmartPort,
     /// Smart Port 2
@@ -46,6 +54,22 @@ pub struct Peripherals {
     pub port20: SmartPort,
     /// Smart Port 21
     pub port21: SmartPort,
+    /// ADI Port A
+    pub port_a: AdiPort,
+    /// ADI Port B
+    pub port_b: AdiPort,
+    /// ADI Port C
+    pub port_c: AdiPort,
+    /// ADI Port D
+    pub port_d: AdiPort,
+    /// ADI Port E
+    pub port_e: AdiPort,
+    /// ADI Port F
+    pub port_f: AdiPort,
+    /// ADI Port G
+    pub port_g: AdiPort,
+    /// ADI Port H
+    pub port_h: AdiPort,
 }
 
 static mut PERIPHERALS_TAKEN: bool = false;
@@ -81,6 +105,8 @@ impl Peripherals {
         PERIPHERALS_TAKEN = true;
 
         Peripherals {
+            master_controller: Controller::new(ControllerId::Master),
+            partner_controller: Controller::new(ControllerId::Partner),
             port01: SmartPort::new(1),
             port02: SmartPort::new(2),
             port03: SmartPort::new(3),
@@ -102,6 +128,14 @@ impl Peripherals {
             port19: SmartPort::new(19),
             port20: SmartPort::new(20),
             port21: SmartPort::new(21),
+            port_a: AdiPort::new(1),
+            port_b: AdiPort::new(2),
+            port_c: AdiPort::new(3),
+            port_d: AdiPort::new(4),
+            port_e: AdiPort::new(5),
+            port_f: AdiPort::new(6),
+            port_g: AdiPort::new(7),
+            port_h: AdiPort::new(8),
         }
     }
 }
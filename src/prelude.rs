@@ -2,6 +2,7 @@
 
 pub use crate::entry;
 
+pub use crate::error::Error;
 pub use crate::io::{eprint, eprintln, print, println};
 pub use crate::robot::Robot;
 pub use crate::rtos::Context;
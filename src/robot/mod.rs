@@ -0,0 +1,253 @@
+//! For use with the [`entry`] macro.
+
+use core::{iter, time::Duration};
+
+use crate::{
+    bindings,
+    error::Error,
+    io::eprintln,
+    rtos::{Context, Loop, Mutex, Task},
+    select,
+};
+
+mod selector;
+
+pub use selector::*;
+
+/// How often the motors are stopped again while a new mode waits for a
+/// previous mode which has overrun its transition timeout.
+const STOP_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Specifies the name, priority and stack depth of the task which the
+/// [`entry`](crate::entry) macro spawns for a competition mode.
+///
+/// # Examples
+///
+/// ```
+/// impl Robot for FooBot {
+///     const AUTONOMOUS_TASK: ModeTask = ModeTask::new("autonomous").stack_depth(0x8000);
+///     // ...
+/// }
+/// ```
+pub struct ModeTask {
+    /// The name of the task.
+    pub name: &'static str,
+    /// The priority of the task.
+    pub priority: u32,
+    /// The stack depth of the task.
+    pub stack_depth: u16,
+}
+
+impl ModeTask {
+    /// Creates a new mode task specification with the given name and the
+    /// default priority and stack depth.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            priority: Task::DEFAULT_PRIORITY,
+            stack_depth: Task::DEFAULT_STACK_DEPTH,
+        }
+    }
+
+    /// Sets the priority of the task.
+    pub const fn priority(self, priority: u32) -> Self {
+        Self { priority, ..self }
+    }
+
+    /// Sets the stack depth of the task.
+    pub const fn stack_depth(self, stack_depth: u16) -> Self {
+        Self {
+            stack_depth,
+            ..self
+        }
+    }
+}
+
+/// A trait representing a competition-ready VEX Robot.
+///
+/// When a competition mode method returns an error, the [`entry`](crate::entry)
+/// macro logs it along with the mode and task name and stops every motor; the
+/// robot then stays idle until the next mode begins.
+///
+/// When the competition mode changes, the [`Context`] of the previous mode is
+/// cancelled, and the new mode does not start until the previous mode method
+/// has returned. If it does not return within
+/// [`Robot::MODE_TRANSITION_TIMEOUT`], every motor is held stopped until it
+/// does.
+pub trait Robot {
+    /// How long a new competition mode waits for the previous mode method to
+    /// return after its context is cancelled, before it starts stopping the
+    /// motors.
+    ///
+    /// Once this has passed, every motor (including legacy motors on
+    /// three-wire ports) is commanded to stop repeatedly until the previous
+    /// mode method returns, and only then does the new mode begin. A mode
+    /// method which ignores its context therefore still delays the next mode
+    /// indefinitely, and any other actuators it commands, such as pneumatics
+    /// and LED strips, are left as it sets them.
+    const MODE_TRANSITION_TIMEOUT: Duration = Duration::from_millis(500);
+    /// The task in which [`Robot::autonomous()`] runs.
+    const AUTONOMOUS_TASK: ModeTask = ModeTask::new("autonomous");
+    /// The task in which [`Robot::opcontrol()`] runs.
+    const OPCONTROL_TASK: ModeTask = ModeTask::new("opcontrol");
+    /// The task in which [`Robot::disabled()`] runs.
+    const DISABLED_TASK: ModeTask = ModeTask::new("disabled");
+    /// The task in which [`Robot::competition_initialize()`] runs.
+    const COMPETITION_INITIALIZE_TASK: ModeTask = ModeTask::new("competition_initialize");
+
+    /// Runs at startup. This should be non-blocking, since the FreeRTOS
+    /// scheduler doesn't start until it returns.
+    ///
+    /// If this fails, the error is logged and none of the other methods are
+    /// ever called.
+    fn initialize() -> Result<Self, Error>
+    where
+        Self: Sized;
+    /// Runs during the autonomous period.
+    fn autonomous(&self, ctx: Context) -> Result<(), Error>;
+    /// Runs during the opcontrol period.
+    fn opcontrol(&self, ctx: Context) -> Result<(), Error>;
+    /// Runs when the robot is disabled.
+    fn disabled(&self, ctx: Context) -> Result<(), Error>;
+    /// Runs after initialization when the robot is connected to a competition
+    /// control system, and whenever it is reconnected. This is typically used
+    /// for autonomous selectors. Does nothing by default.
+    fn competition_initialize(&self, _ctx: Context) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub struct ContextWrapper(Mutex<ModeState>);
+
+struct ModeState {
+    generation: u32,
+    current: Option<(Context, Context)>,
+}
+
+impl ContextWrapper {
+    #[doc(hidden)]
+    #[inline]
+    pub fn new() -> Self {
+        Self(Mutex::new(ModeState {
+            generation: 0,
+            current: None,
+        }))
+    }
+
+    /// Cancels the context of the current mode and makes a new mode current,
+    /// returning the context of the new mode along with the signal which the
+    /// previous mode task cancels when it finishes, if there was one.
+    fn replace(&self) -> (ModeGuard<'_>, Option<Context>) {
+        let mut state = self.0.lock();
+        let previous = state.current.take().map(|(ctx, finished)| {
+            ctx.cancel();
+            finished
+        });
+        let ctx = Context::new_global();
+        let finished = Context::new_global();
+        state.generation = state.generation.wrapping_add(1);
+        state.current = Some((ctx.clone(), finished.clone()));
+        (
+            ModeGuard {
+                wrapper: self,
+                generation: state.generation,
+                ctx,
+                finished,
+            },
+            previous,
+        )
+    }
+}
+
+/// Represents the running task of a competition mode. Signals the next mode
+/// when dropped.
+struct ModeGuard<'a> {
+    wrapper: &'a ContextWrapper,
+    generation: u32,
+    ctx: Context,
+    finished: Context,
+}
+
+impl ModeGuard<'_> {
+    /// Checks whether no other mode has started since this one.
+    fn is_current(&self) -> bool {
+        self.wrapper.0.lock().generation == self.generation
+    }
+}
+
+impl Drop for ModeGuard<'_> {
+    fn drop(&mut self) {
+        self.finished.cancel();
+    }
+}
+
+#[doc(hidden)]
+pub fn spawn_mode<F>(
+    mode: &'static str,
+    task: ModeTask,
+    timeout: Duration,
+    wrapper: &'static ContextWrapper,
+    f: F,
+) where
+    F: FnOnce(Context) -> Result<(), Error> + Send + 'static,
+{
+    // The previous mode is cancelled before the new task starts, so that the
+    // order in which PROS calls the mode functions decides which mode is
+    // current, regardless of when the tasks get to run.
+    let (guard, previous) = wrapper.replace();
+    if let Err(err) = Task::spawn_ext(task.name, task.priority, task.stack_depth, move || {
+        if let Some(previous) = previous {
+            let mut l = Loop::new(timeout);
+            let finished = select! {
+                _ = previous.done() => true,
+                _ = l.next() => false,
+            };
+            if !finished {
+                eprintln!(
+                    "{}: previous mode did not finish within {:?}; stopping motors until it does",
+                    mode, timeout
+                );
+                // The previous task may still be commanding the motors, so
+                // keep overriding it until it exits.
+                let mut l = Loop::new(STOP_INTERVAL);
+                loop {
+                    stop_motors();
+                    select! {
+                        _ = previous.done() => break,
+                        _ = l.next() => continue,
+                    }
+                }
+            }
+        }
+        if let Err(err) = f(guard.ctx.clone()) {
+            report_error(mode, task.name, &err);
+            // Leave the motors alone if the next mode has already started.
+            if guard.is_current() {
+                stop_motors();
+            }
+        }
+    }) {
+        report_error(mode, task.name, &err);
+    }
+}
+
+#[doc(hidden)]
+pub fn report_error(mode: &str, task: &str, err: &Error) {
+    eprintln!("{} failed in task {:?}: {:?}", mode, task, err);
+}
+
+/// Commands every motor on the brain to stop, including legacy motors on the
+/// brain's three-wire ports and on ADI expanders. Ports without a motor are
+/// ignored.
+fn stop_motors() {
+    for port in 1..22 {
+        unsafe { bindings::motor_move(port, 0) };
+    }
+    for smart_port in (1..22).chain(iter::once(bindings::INTERNAL_ADI_PORT as u8)) {
+        for adi_port in 1..9 {
+            unsafe { bindings::ext_adi_motor_stop(smart_port, adi_port) };
+        }
+    }
+}
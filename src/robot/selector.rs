@@ -0,0 +1,283 @@
+//! Autonomous routine selection.
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    controller::{Button, Controller},
+    error::{from_errno, Error},
+    io::eprintln,
+    rtos::{Context, Loop, Mutex, Selectable},
+    select,
+    util::as_cstring,
+};
+
+/// How often the selector menu checks for button presses.
+const MENU_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The longest routine name which is read back from the SD card.
+const MAX_NAME_LEN: usize = 256;
+
+type Routine = Box<dyn Fn(Context) -> Result<(), Error> + Send + Sync>;
+
+/// Lets the driver choose one of several named autonomous routines before a
+/// match, and runs the chosen routine during the autonomous period.
+///
+/// The choice is made with the left and right arrow buttons of a controller or
+/// the left and right buttons of the brain screen, and is saved to the SD card
+/// so that it survives a restart of the program.
+///
+/// # Examples
+///
+/// ```
+/// struct Bot {
+///     selector: AutonSelector,
+///     controller: Controller,
+/// }
+///
+/// impl Robot for Bot {
+///     fn initialize() -> Result<Self, Error> {
+///         let selector = AutonSelector::new()
+///             .add("left", |ctx| left_auton(ctx))
+///             .add("right", |ctx| right_auton(ctx));
+///         selector.load()?;
+///         let peripherals = Peripherals::take().unwrap();
+///         Ok(Bot {
+///             selector,
+///             controller: peripherals.master_controller,
+///         })
+///     }
+///     fn competition_initialize(&self, ctx: Context) -> Result<(), Error> {
+///         self.selector.choose(ctx, Some(&self.controller))
+///     }
+///     fn autonomous(&self, ctx: Context) -> Result<(), Error> {
+///         self.selector.run(ctx)
+///     }
+///     // ...
+/// }
+/// ```
+pub struct AutonSelector {
+    routines: Vec<(String, Routine)>,
+    selected: Mutex<usize>,
+    path: String,
+}
+
+impl AutonSelector {
+    /// The default file in which the choice of routine is saved.
+    pub const DEFAULT_PATH: &'static str = "/usd/auton.txt";
+
+    /// Creates a new selector with no routines.
+    pub fn new() -> Self {
+        Self {
+            routines: Vec::new(),
+            selected: Mutex::new(0),
+            path: Self::DEFAULT_PATH.to_string(),
+        }
+    }
+
+    /// Sets the file in which the choice of routine is saved.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.to_string();
+        self
+    }
+
+    /// Registers a named routine. The first routine registered is selected
+    /// until another choice is made or loaded.
+    pub fn add(
+        mut self,
+        name: &str,
+        routine: impl Fn(Context) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Self {
+        self.routines.push((name.to_string(), Box::new(routine)));
+        self
+    }
+
+    /// Gets the names of the registered routines, in the order they were
+    /// registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.routines.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Gets the name of the selected routine, if any routines are registered.
+    pub fn selected(&self) -> Option<&str> {
+        self.routines
+            .get(*self.selected.lock())
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Selects the routine at the given index and saves the choice.
+    pub fn select(&self, index: usize) -> Result<(), Error> {
+        let (name, _) = self
+            .routines
+            .get(index)
+            .ok_or_else(|| Error::Custom(format!("no autonomous routine at index {}", index)))?;
+        *self.selected.lock() = index;
+        self.save(name)
+    }
+
+    /// Selects the routine with the given name and saves the choice.
+    pub fn select_by_name(&self, name: &str) -> Result<(), Error> {
+        self.select(
+            self.find(name)
+                .ok_or_else(|| Error::Custom(format!("no autonomous routine named {:?}", name)))?,
+        )
+    }
+
+    /// Loads the saved choice of routine, returning whether a saved choice was
+    /// found which matches a registered routine. A missing file is not an
+    /// error, since nothing has been saved on a fresh SD card.
+    pub fn load(&self) -> Result<bool, Error> {
+        let file = open(&self.path, b"r\0")?;
+        if file.is_null() {
+            return Ok(false);
+        }
+        let mut buf = [0u8; MAX_NAME_LEN];
+        let len = unsafe {
+            let len = libc::fread(buf.as_mut_ptr() as *mut _, 1, buf.len(), file);
+            libc::fclose(file);
+            len
+        };
+        let name = core::str::from_utf8(&buf[..len]).unwrap_or("").trim();
+        Ok(match self.find(name) {
+            Some(index) => {
+                *self.selected.lock() = index;
+                true
+            }
+            None => false,
+        })
+    }
+
+    /// Shows a menu for choosing a routine on the brain screen and, if one is
+    /// given, the controller screen, until `ctx` is cancelled. This is intended
+    /// to be called from the `disabled` or `competition_initialize` method of a
+    /// [`Robot`](super::Robot).
+    pub fn choose(&self, ctx: Context, controller: Option<&Controller>) -> Result<(), Error> {
+        if self.routines.is_empty() {
+            return Err(no_routines());
+        }
+        unsafe {
+            if !bindings::lcd_is_initialized() {
+                bindings::lcd_initialize();
+            }
+        }
+        let mut last_lcd = 0;
+        let mut shown = None;
+        let mut l = Loop::new(MENU_POLL_INTERVAL);
+        loop {
+            let lcd = unsafe { bindings::lcd_read_buttons() } as u32;
+            let (mut left, mut right) = (
+                lcd & !last_lcd & bindings::LCD_BTN_LEFT != 0,
+                lcd & !last_lcd & bindings::LCD_BTN_RIGHT != 0,
+            );
+            last_lcd = lcd;
+            if let Some(controller) = controller {
+                left |= was_pressed(controller, Button::Left);
+                right |= was_pressed(controller, Button::Right);
+            }
+
+            let n = self.routines.len();
+            let index = *self.selected.lock();
+            let next = if left {
+                Some((index + n - 1) % n)
+            } else if right {
+                Some((index + 1) % n)
+            } else {
+                None
+            };
+            if let Some(next) = next {
+                // The choice still applies to this run if it cannot be saved
+                // (e.g., because there is no SD card).
+                if let Err(err) = self.select(next) {
+                    eprintln!("failed to save autonomous selection: {:?}", err);
+                }
+            }
+
+            let index = *self.selected.lock();
+            if shown != Some(index) {
+                let name = &self.routines[index].0;
+                set_lcd_line(0, "Autonomous:")?;
+                set_lcd_line(1, name)?;
+                set_lcd_line(2, &format!("< {}/{} >", index + 1, n))?;
+                if let Some(controller) = controller {
+                    controller.print(0, 0, format_args!("Auton: {:<12}", name))?;
+                }
+                shown = Some(index);
+            }
+
+            select! {
+                _ = l.next() => continue,
+                _ = ctx.done() => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the selected routine. This is intended to be called from
+    /// [`Robot::autonomous()`](crate::robot::Robot::autonomous()).
+    pub fn run(&self, ctx: Context) -> Result<(), Error> {
+        let index = *self.selected.lock();
+        let (_, routine) = self.routines.get(index).ok_or_else(no_routines)?;
+        routine(ctx)
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.routines.iter().position(|(n, _)| n == name)
+    }
+
+    fn save(&self, name: &str) -> Result<(), Error> {
+        let file = open(&self.path, b"w\0")?;
+        if file.is_null() {
+            return Err(from_errno());
+        }
+        let written = unsafe {
+            let written = libc::fwrite(name.as_ptr() as *const _, 1, name.len(), file);
+            libc::fclose(file);
+            written
+        };
+        if written == name.len() {
+            Ok(())
+        } else {
+            Err(from_errno())
+        }
+    }
+}
+
+impl Default for AutonSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn open(path: &str, mode: &[u8]) -> Result<*mut libc::FILE, Error> {
+    as_cstring(path, |cpath| {
+        Ok(unsafe { libc::fopen(cpath.into_raw(), mode.as_ptr() as *const _) })
+    })
+}
+
+#[inline]
+fn set_lcd_line(line: i16, text: &str) -> Result<(), Error> {
+    as_cstring(text, |ctext| {
+        unsafe { bindings::lcd_set_text(line, ctext.into_raw()) };
+        Ok(())
+    })
+}
+
+#[inline]
+/// Checks once, without blocking, whether a controller button has been pressed.
+fn was_pressed(controller: &Controller, button: Button) -> bool {
+    // A disconnected controller is not a reason to stop the menu, so errors are
+    // treated as no press.
+    matches!(controller.pressed(button).poll(), Ok(Ok(())))
+}
+
+#[inline]
+fn no_routines() -> Error {
+    Error::Custom("no autonomous routines registered".to_string())
+}
@@ -0,0 +1,93 @@
+//! Rotation sensor API.
+
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, Error},
+    units::{round, Angle, AngularVelocity},
+};
+
+/// A struct which represents a V5 smart port configured as a rotation sensor.
+pub struct RotationSensor {
+    port: u8,
+}
+
+impl RotationSensor {
+    /// Constructs a new rotation sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_rotation_sensor()`] instead.
+    pub unsafe fn new(port: u8) -> RotationSensor {
+        assert!((1..22).contains(&port));
+        RotationSensor { port }
+    }
+
+    /// Resets the position of the sensor to match its current absolute angle.
+    pub fn reset(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::rotation_reset(self.port) })?;
+        Ok(())
+    }
+
+    /// Resets the position of the sensor to zero.
+    pub fn reset_position(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::rotation_reset_position(self.port) })?;
+        Ok(())
+    }
+
+    /// Sets the position of the sensor. The angle is rounded to the nearest
+    /// centidegree.
+    pub fn set_position(&self, position: Angle) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::rotation_set_position(
+                self.port,
+                round(position.as_degrees() * 100.0) as i32 as u32,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Gets the position of the sensor, which accumulates over multiple
+    /// rotations.
+    pub fn get_position(&self) -> Result<Angle, Error> {
+        Ok(centidegrees(check_pros_err(unsafe {
+            bindings::rotation_get_position(self.port)
+        })?))
+    }
+
+    /// Gets the absolute angle of the sensor, on the range 0° to 360°.
+    pub fn get_angle(&self) -> Result<Angle, Error> {
+        Ok(centidegrees(check_pros_err(unsafe {
+            bindings::rotation_get_angle(self.port)
+        })?))
+    }
+
+    /// Gets the angular velocity of the sensor.
+    pub fn get_velocity(&self) -> Result<AngularVelocity, Error> {
+        let v = check_pros_err(unsafe { bindings::rotation_get_velocity(self.port) })?;
+        Ok(AngularVelocity::from_degrees_per_second(v as f64 / 100.0))
+    }
+
+    /// Sets whether the sensor is reversed.
+    pub fn set_reversed(&self, reverse: bool) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::rotation_set_reversed(self.port, reverse) })?;
+        Ok(())
+    }
+
+    /// Checks whether the sensor is reversed.
+    pub fn is_reversed(&self) -> Result<bool, Error> {
+        Ok(check_pros_err(unsafe { bindings::rotation_get_reversed(self.port) })? != 0)
+    }
+
+    /// Sets the interval at which the sensor reports new data. The interval is
+    /// rounded down to a multiple of 5 ms, with a minimum of 5 ms.
+    pub fn set_data_rate(&self, rate: Duration) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::rotation_set_data_rate(self.port, rate.as_millis() as u32)
+        })?;
+        Ok(())
+    }
+}
+
+#[inline]
+fn centidegrees(value: i32) -> Angle {
+    Angle::from_degrees(value as f64 / 100.0)
+}
@@ -302,6 +302,44 @@ pub fn select_either<'a, T: 'a>(
     EitherSelect(fst, snd, PhantomData)
 }
 
+/// Creates a new [`Selectable`] event which occurs when the given function
+/// produces a value. The function is called whenever the event is polled, and
+/// at least once every `interval`; this is intended for conditions which can
+/// only be detected by polling (e.g., device state), since no task is notified
+/// when they change.
+#[inline]
+pub fn select_poll<'a, T: 'a>(
+    interval: Duration,
+    f: impl FnMut() -> Option<T> + 'a,
+) -> impl Selectable<T> + 'a {
+    struct PollSelect<T, F: FnMut() -> Option<T>> {
+        interval: Duration,
+        next: Duration,
+        f: F,
+    }
+
+    impl<T, F: FnMut() -> Option<T>> Selectable<T> for PollSelect<T, F> {
+        fn poll(mut self) -> Result<T, Self> {
+            match (self.f)() {
+                Some(r) => Ok(r),
+                None => {
+                    self.next = time_since_start() + self.interval;
+                    Err(self)
+                }
+            }
+        }
+        fn sleep(&self) -> GenericSleep {
+            GenericSleep::Timestamp(self.next)
+        }
+    }
+
+    PollSelect {
+        interval,
+        next: time_since_start(),
+        f,
+    }
+}
+
 mod context;
 mod event;
 mod r#loop;
@@ -0,0 +1,126 @@
+//! Generic serial API.
+
+use alloc::string::ToString;
+use core::time::Duration;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, Error},
+    rtos::{select_poll, Context, Selectable},
+    select,
+};
+
+/// How often a data available event re-checks the input buffer.
+const DATA_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// How often a flush re-checks the output buffer.
+const FLUSH_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A struct which represents a V5 smart port configured as a generic serial
+/// (RS-485) port.
+pub struct SerialPort {
+    port: u8,
+    write_capacity: usize,
+}
+
+impl SerialPort {
+    /// Constructs a new serial port unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_serial()`] instead.
+    pub unsafe fn new(port: u8, baudrate: i32) -> Result<SerialPort, Error> {
+        assert!((1..22).contains(&port));
+        check_pros_err(bindings::serial_enable(port))?;
+        let mut serial = SerialPort {
+            port,
+            write_capacity: 0,
+        };
+        serial.set_baudrate(baudrate)?;
+        // Nothing has been written yet, so the whole output buffer is free.
+        serial.write_capacity = serial.get_write_free()?;
+        Ok(serial)
+    }
+
+    /// Sets the baud rate of the port.
+    pub fn set_baudrate(&self, baudrate: i32) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::serial_set_baudrate(self.port, baudrate) })?;
+        Ok(())
+    }
+
+    /// Gets the number of bytes available to be read from the input buffer.
+    pub fn get_read_avail(&self) -> Result<usize, Error> {
+        Ok(check_pros_err(unsafe { bindings::serial_get_read_avail(self.port) })? as usize)
+    }
+
+    /// Gets the number of bytes which can be written to the output buffer
+    /// without blocking.
+    pub fn get_write_free(&self) -> Result<usize, Error> {
+        Ok(check_pros_err(unsafe { bindings::serial_get_write_free(self.port) })? as usize)
+    }
+
+    /// Reads as many bytes as are available (up to the length of `buf`) into
+    /// `buf`, returning the number of bytes read. Does not block.
+    pub fn read(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::serial_read(self.port, buf.as_mut_ptr(), buf.len() as i32)
+        })? as usize)
+    }
+
+    /// Reads a single byte from the input buffer, if one is available.
+    pub fn read_byte(&self) -> Result<Option<u8>, Error> {
+        let b = check_pros_err(unsafe { bindings::serial_read_byte(self.port) })?;
+        Ok(if b < 0 { None } else { Some(b as u8) })
+    }
+
+    /// Gets the next byte in the input buffer without removing it, if one is
+    /// available.
+    pub fn peek_byte(&self) -> Result<Option<u8>, Error> {
+        let b = check_pros_err(unsafe { bindings::serial_peek_byte(self.port) })?;
+        Ok(if b < 0 { None } else { Some(b as u8) })
+    }
+
+    /// Writes as many bytes from `buf` as fit in the output buffer, returning
+    /// the number of bytes written. Does not block.
+    pub fn write(&self, buf: &[u8]) -> Result<usize, Error> {
+        Ok(check_pros_err(unsafe {
+            bindings::serial_write(self.port, buf.as_ptr() as *mut u8, buf.len() as i32)
+        })? as usize)
+    }
+
+    /// Writes a single byte to the output buffer.
+    pub fn write_byte(&self, b: u8) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::serial_write_byte(self.port, b) })?;
+        Ok(())
+    }
+
+    /// Blocks until every byte in the output buffer has been transmitted, or
+    /// until `ctx` is cancelled, in which case an error is returned.
+    ///
+    /// PROS does not report the size of the output buffer, so it is taken to
+    /// be the free space reported when the port was enabled.
+    pub fn flush(&self, ctx: Context) -> Result<(), Error> {
+        let drained = select_poll(FLUSH_POLL_INTERVAL, move || match self.get_write_free() {
+            Ok(free) if free < self.write_capacity => None,
+            Ok(_) => Some(Ok(())),
+            Err(err) => Some(Err(err)),
+        });
+        select! {
+            r = drained => r,
+            _ = ctx.done() => Err(Error::Custom("serial flush was cancelled".to_string())),
+        }
+    }
+
+    /// Clears the input and output buffers of the port, discarding any data
+    /// which has not yet been read or transmitted.
+    pub fn clear_buffers(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::serial_flush(self.port) })?;
+        Ok(())
+    }
+
+    /// A [`Selectable`] event which occurs when data is available to be read,
+    /// producing the number of bytes available.
+    pub fn data_available<'a>(&'a self) -> impl Selectable<Result<usize, Error>> + 'a {
+        select_poll(DATA_POLL_INTERVAL, move || match self.get_read_avail() {
+            Ok(0) => None,
+            r => Some(r),
+        })
+    }
+}
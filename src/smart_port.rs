@@ -1,6 +1,19 @@
 //! SmartPort.
 
-use crate::motor::{Gearset, Motor};
+use crate::{
+    adi_port::AdiExpander,
+    distance::DistanceSensor,
+    error::Error,
+    gps::GpsSensor,
+    imu::InertialSensor,
+    link::{Link, LinkType},
+    motor::{Motor, MotorConfig},
+    optical::OpticalSensor,
+    rotation::RotationSensor,
+    serial::SerialPort,
+    vision::VisionSensor,
+};
+
 /// A struct which represents an unconfigured smart port.
 pub struct SmartPort {
     port: u8,
@@ -17,18 +30,80 @@ impl SmartPort {
         Self { port }
     }
 
-    /// Converts a `SmartPort` into a [`Motor`](crate::motor::Motor).
+    /// Converts a `SmartPort` into a [`Motor`](crate::motor::Motor), applying
+    /// every setting in the given configuration. A
+    /// [`Gearset`](crate::motor::Gearset) or a `(Gearset, bool)` pair of
+    /// gearset and reversal may be given in place of a full
+    /// [`MotorConfig`](crate::motor::MotorConfig).
     ///
     /// # Examples
     ///
     /// ```
-    /// use vex_rt as rt;
-    /// let peripherals = rt::Peripherals::take();
-    /// let gearset = rt::Gearset::ThirtySixToOne;
-    /// let is_reversed = false;
-    /// let motor01 = peripherals.port01.as_motor(gearset, is_reversed);
+    /// use vex_rt::motor::{BrakeMode, Gearset, MotorConfig};
+    /// use vex_rt::peripherals::Peripherals;
+    /// let peripherals = Peripherals::take().unwrap();
+    /// let motor01 = peripherals
+    ///     .port01
+    ///     .as_motor((Gearset::ThirtySixToOne, false))
+    ///     .unwrap();
+    /// let config = MotorConfig::new(Gearset::EighteenToOne).brake_mode(BrakeMode::Hold);
+    /// let motor02 = peripherals.port02.as_motor(config).unwrap();
     /// ```
-    pub fn as_motor(self, gearset: Gearset, is_reversed: bool) -> Motor {
-        unsafe { Motor::new(self.port, gearset, is_reversed) }
+    pub fn as_motor(self, config: impl Into<MotorConfig>) -> Result<Motor, Error> {
+        unsafe { Motor::from_config(self.port, config.into()) }
+    }
+
+    /// Converts a `SmartPort` into a
+    /// [`RotationSensor`](crate::rotation::RotationSensor).
+    pub fn as_rotation_sensor(self) -> RotationSensor {
+        unsafe { RotationSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into an
+    /// [`InertialSensor`](crate::imu::InertialSensor).
+    pub fn as_imu(self) -> InertialSensor {
+        unsafe { InertialSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into a
+    /// [`DistanceSensor`](crate::distance::DistanceSensor).
+    pub fn as_distance_sensor(self) -> DistanceSensor {
+        unsafe { DistanceSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into an
+    /// [`OpticalSensor`](crate::optical::OpticalSensor).
+    pub fn as_optical_sensor(self) -> OpticalSensor {
+        unsafe { OpticalSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into a [`GpsSensor`](crate::gps::GpsSensor).
+    pub fn as_gps(self) -> GpsSensor {
+        unsafe { GpsSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into a
+    /// [`VisionSensor`](crate::vision::VisionSensor).
+    pub fn as_vision_sensor(self) -> VisionSensor {
+        unsafe { VisionSensor::new(self.port) }
+    }
+
+    /// Converts a `SmartPort` into a generic
+    /// [`SerialPort`](crate::serial::SerialPort) with the given baud rate.
+    pub fn as_serial(self, baudrate: i32) -> Result<SerialPort, Error> {
+        unsafe { SerialPort::new(self.port, baudrate) }
+    }
+
+    /// Converts a `SmartPort` into a VEXlink radio
+    /// [`Link`](crate::link::Link) with the given name and role.
+    pub fn as_link(self, name: &str, link_type: LinkType) -> Result<Link, Error> {
+        unsafe { Link::new(self.port, name, link_type) }
+    }
+
+    /// Converts a `SmartPort` into an
+    /// [`AdiExpander`](crate::adi_port::AdiExpander), whose ports can be used
+    /// in the same way as the brain's onboard ADI ports.
+    pub fn as_adi_expander(self) -> AdiExpander {
+        unsafe { AdiExpander::new(self.port) }
     }
 }
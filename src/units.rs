@@ -0,0 +1,230 @@
+//! Strongly typed physical quantities.
+
+use core::{
+    f64::consts::PI,
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+macro_rules! quantity_ops {
+    ($t:ident) => {
+        impl Add for $t {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+    };
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+/// Represents an electric potential.
+pub struct Voltage(f64);
+
+impl Voltage {
+    /// The largest voltage magnitude which can be applied to a V5 motor.
+    pub const MAX: Self = Self(12.0);
+
+    #[inline]
+    /// Creates a voltage from a value in volts.
+    pub const fn from_volts(volts: f64) -> Self {
+        Self(volts)
+    }
+
+    #[inline]
+    /// Creates a voltage from a value in millivolts.
+    pub fn from_millivolts(millivolts: i32) -> Self {
+        Self(millivolts as f64 / 1000.0)
+    }
+
+    #[inline]
+    /// Gets the voltage in volts.
+    pub fn as_volts(self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    /// Gets the voltage in millivolts, rounded to the nearest integer.
+    pub fn as_millivolts(self) -> i32 {
+        round(self.0 * 1000.0) as i32
+    }
+
+    #[inline]
+    /// Clamps the voltage to the range which can be applied to a V5 motor.
+    pub fn clamped(self) -> Self {
+        clamp(self, Self::MAX)
+    }
+}
+
+quantity_ops!(Voltage);
+
+impl Display for Voltage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} V", self.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+/// Represents a rate of rotation.
+pub struct AngularVelocity(f64);
+
+impl AngularVelocity {
+    #[inline]
+    /// Creates an angular velocity from a value in revolutions per minute.
+    pub const fn from_rpm(rpm: f64) -> Self {
+        Self(rpm)
+    }
+
+    #[inline]
+    /// Creates an angular velocity from a value in degrees per second.
+    pub fn from_degrees_per_second(dps: f64) -> Self {
+        Self(dps / 6.0)
+    }
+
+    #[inline]
+    /// Creates an angular velocity from a value in radians per second.
+    pub fn from_radians_per_second(rps: f64) -> Self {
+        Self(rps * 30.0 / PI)
+    }
+
+    #[inline]
+    /// Gets the angular velocity in revolutions per minute.
+    pub fn as_rpm(self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    /// Gets the angular velocity in degrees per second.
+    pub fn as_degrees_per_second(self) -> f64 {
+        self.0 * 6.0
+    }
+
+    #[inline]
+    /// Gets the angular velocity in radians per second.
+    pub fn as_radians_per_second(self) -> f64 {
+        self.0 * PI / 30.0
+    }
+
+    #[inline]
+    /// Clamps the angular velocity to the range `-max..=max`.
+    pub fn clamped(self, max: Self) -> Self {
+        clamp(self, max)
+    }
+}
+
+quantity_ops!(AngularVelocity);
+
+impl Display for AngularVelocity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} RPM", self.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+/// Represents an angle or angular position.
+pub struct Angle(f64);
+
+impl Angle {
+    #[inline]
+    /// Creates an angle from a value in degrees.
+    pub const fn from_degrees(degrees: f64) -> Self {
+        Self(degrees)
+    }
+
+    #[inline]
+    /// Creates an angle from a value in full rotations.
+    pub fn from_rotations(rotations: f64) -> Self {
+        Self(rotations * 360.0)
+    }
+
+    #[inline]
+    /// Creates an angle from a value in radians.
+    pub fn from_radians(radians: f64) -> Self {
+        Self(radians * 180.0 / PI)
+    }
+
+    #[inline]
+    /// Gets the angle in degrees.
+    pub fn as_degrees(self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    /// Gets the angle in full rotations.
+    pub fn as_rotations(self) -> f64 {
+        self.0 / 360.0
+    }
+
+    #[inline]
+    /// Gets the angle in radians.
+    pub fn as_radians(self) -> f64 {
+        self.0 * PI / 180.0
+    }
+}
+
+quantity_ops!(Angle);
+
+impl Display for Angle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
+
+#[inline]
+fn clamp<T: PartialOrd + Neg<Output = T> + Copy>(value: T, max: T) -> T {
+    if value > max {
+        max
+    } else if value < -max {
+        -max
+    } else {
+        value
+    }
+}
+
+#[inline]
+pub(crate) fn round(value: f64) -> f64 {
+    // `f64::round` is not available in `core`.
+    if value < 0.0 {
+        -((-value + 0.5) as i64 as f64)
+    } else {
+        (value + 0.5) as i64 as f64
+    }
+}
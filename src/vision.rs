@@ -0,0 +1,361 @@
+//! Vision sensor API.
+
+use alloc::vec::Vec;
+
+use crate::{
+    bindings,
+    error::{check_pros_err, from_errno, get_errno, Error, PROS_ERR},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// An enum which represents the possible kinds of object detected by a vision
+/// sensor.
+pub enum VisionObjectType {
+    /// An object matching a single signature.
+    Normal,
+    /// An object matching a color code.
+    ColorCode,
+    /// A line.
+    Line,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents an object detected by a vision sensor. Coordinates are in pixels,
+/// relative to the zero point set with [`VisionSensor::set_zero_point()`].
+pub struct VisionObject {
+    /// The signature ID or color code which the object matched.
+    pub signature: u16,
+    /// The kind of object.
+    pub object_type: VisionObjectType,
+    /// The x coordinate of the left edge of the object.
+    pub left: i16,
+    /// The y coordinate of the top edge of the object.
+    pub top: i16,
+    /// The width of the object.
+    pub width: i16,
+    /// The height of the object.
+    pub height: i16,
+    /// The angle of the object, for color codes.
+    pub angle: u16,
+    /// The x coordinate of the middle of the object.
+    pub x_middle: i16,
+    /// The y coordinate of the middle of the object.
+    pub y_middle: i16,
+}
+
+impl VisionObject {
+    fn from_vision_object_s_t(obj: bindings::vision_object_s_t) -> Result<Self, Error> {
+        if obj.signature as u32 == bindings::VISION_OBJECT_ERR_SIG {
+            return Err(from_errno());
+        }
+        Ok(VisionObject {
+            signature: obj.signature,
+            object_type: match obj.type_ {
+                bindings::vision_object_type_E_VISION_OBJECT_COLOR_CODE => {
+                    VisionObjectType::ColorCode
+                }
+                bindings::vision_object_type_E_VISION_OBJECT_LINE => VisionObjectType::Line,
+                _ => VisionObjectType::Normal,
+            },
+            left: obj.left_coord,
+            top: obj.top_coord,
+            width: obj.width,
+            height: obj.height,
+            angle: obj.angle,
+            x_middle: obj.x_middle_coord,
+            y_middle: obj.y_middle_coord,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Represents a color signature which a vision sensor can detect. The values
+/// for a signature can be copied from the vision utility.
+pub struct VisionSignature {
+    /// The ID of the signature, on the range 1 to 7.
+    pub id: u8,
+    /// The scale factor of the signature's color range.
+    pub range: f32,
+    /// The minimum value on the u axis.
+    pub u_min: i32,
+    /// The maximum value on the u axis.
+    pub u_max: i32,
+    /// The mean value on the u axis.
+    pub u_mean: i32,
+    /// The minimum value on the v axis.
+    pub v_min: i32,
+    /// The maximum value on the v axis.
+    pub v_max: i32,
+    /// The mean value on the v axis.
+    pub v_mean: i32,
+    /// The RGB color of the signature.
+    pub rgb: u32,
+    /// The type of the signature.
+    pub signature_type: u32,
+}
+
+impl VisionSignature {
+    fn from_vision_signature_s_t(sig: bindings::vision_signature_s_t) -> Result<Self, Error> {
+        if sig.id as u32 == bindings::VISION_OBJECT_ERR_SIG {
+            return Err(from_errno());
+        }
+        Ok(VisionSignature {
+            id: sig.id,
+            range: sig.range,
+            u_min: sig.u_min,
+            u_max: sig.u_max,
+            u_mean: sig.u_mean,
+            v_min: sig.v_min,
+            v_max: sig.v_max,
+            v_mean: sig.v_mean,
+            rgb: sig.rgb,
+            signature_type: sig.type_,
+        })
+    }
+
+    fn to_vision_signature_s_t(&self) -> bindings::vision_signature_s_t {
+        bindings::vision_signature_s_t {
+            id: self.id,
+            _pad: [0; 3],
+            range: self.range,
+            u_min: self.u_min,
+            u_max: self.u_max,
+            u_mean: self.u_mean,
+            v_min: self.v_min,
+            v_max: self.v_max,
+            v_mean: self.v_mean,
+            rgb: self.rgb,
+            type_: self.signature_type,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Represents a color code, which is a combination of signatures detected next
+/// to each other. Created with [`VisionSensor::create_color_code()`].
+pub struct ColorCode(u16);
+
+impl ColorCode {
+    #[inline]
+    /// Gets the raw value of the color code, which is reported as the signature
+    /// of matching [`VisionObject`]s.
+    pub fn value(self) -> u16 {
+        self.0
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// An enum which represents possible origins for vision sensor coordinates.
+pub enum VisionZeroPoint {
+    /// Coordinates are relative to the top-left corner of the field of view.
+    TopLeft,
+    /// Coordinates are relative to the center of the field of view.
+    Center,
+}
+
+/// A struct which represents a V5 smart port configured as a vision sensor.
+pub struct VisionSensor {
+    port: u8,
+}
+
+impl VisionSensor {
+    /// The width of the sensor's field of view in pixels.
+    pub const FOV_WIDTH: u32 = bindings::VISION_FOV_WIDTH;
+
+    /// The height of the sensor's field of view in pixels.
+    pub const FOV_HEIGHT: u32 = bindings::VISION_FOV_HEIGHT;
+
+    /// The maximum number of objects which the sensor reports at once. Reads
+    /// of more objects than this are limited to this many.
+    pub const MAX_OBJECTS: u32 = 16;
+
+    /// Constructs a new vision sensor unsafely. You probably want to use
+    /// [`crate::smart_port::SmartPort::as_vision_sensor()`] instead.
+    pub unsafe fn new(port: u8) -> VisionSensor {
+        assert!((1..22).contains(&port));
+        VisionSensor { port }
+    }
+
+    /// Gets the number of objects currently detected by the sensor.
+    pub fn get_object_count(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::vision_get_object_count(self.port) })
+    }
+
+    /// Gets the `size_id`-th largest object detected by the sensor, where 0 is
+    /// the largest.
+    pub fn get_by_size(&self, size_id: u32) -> Result<VisionObject, Error> {
+        VisionObject::from_vision_object_s_t(unsafe {
+            bindings::vision_get_by_size(self.port, size_id)
+        })
+    }
+
+    /// Gets the `size_id`-th largest object detected by the sensor which
+    /// matches the given signature.
+    pub fn get_by_sig(&self, size_id: u32, sig_id: u8) -> Result<VisionObject, Error> {
+        VisionObject::from_vision_object_s_t(unsafe {
+            bindings::vision_get_by_sig(self.port, size_id, sig_id as u32)
+        })
+    }
+
+    /// Gets the `size_id`-th largest object detected by the sensor which
+    /// matches the given color code.
+    pub fn get_by_code(&self, size_id: u32, code: ColorCode) -> Result<VisionObject, Error> {
+        VisionObject::from_vision_object_s_t(unsafe {
+            bindings::vision_get_by_code(self.port, size_id, code.0)
+        })
+    }
+
+    /// Reads up to `count` objects detected by the sensor, in order of
+    /// decreasing size, starting from the `size_id`-th largest.
+    pub fn read_by_size(&self, size_id: u32, count: u32) -> Result<Vec<VisionObject>, Error> {
+        read_objects(count, |count, arr| unsafe {
+            bindings::vision_read_by_size(self.port, size_id, count, arr)
+        })
+    }
+
+    /// Reads up to `count` objects detected by the sensor which match the
+    /// given signature, in order of decreasing size, starting from the
+    /// `size_id`-th largest.
+    pub fn read_by_sig(
+        &self,
+        size_id: u32,
+        sig_id: u8,
+        count: u32,
+    ) -> Result<Vec<VisionObject>, Error> {
+        read_objects(count, |count, arr| unsafe {
+            bindings::vision_read_by_sig(self.port, size_id, sig_id as u32, count, arr)
+        })
+    }
+
+    /// Reads up to `count` objects detected by the sensor which match the
+    /// given color code, in order of decreasing size, starting from the
+    /// `size_id`-th largest.
+    pub fn read_by_code(
+        &self,
+        size_id: u32,
+        code: ColorCode,
+        count: u32,
+    ) -> Result<Vec<VisionObject>, Error> {
+        read_objects(count, |count, arr| unsafe {
+            bindings::vision_read_by_code(self.port, size_id, code.0, count, arr)
+        })
+    }
+
+    /// Creates a color code from between 2 and 5 signature IDs, in the order
+    /// in which they appear from left to right.
+    pub fn create_color_code(&self, sig_ids: &[u8]) -> Result<ColorCode, Error> {
+        if !(2..=5).contains(&sig_ids.len()) {
+            return Err(Error::Custom(
+                "a color code must consist of between 2 and 5 signatures".into(),
+            ));
+        }
+        let id = |i: usize| sig_ids.get(i).copied().unwrap_or(0) as u32;
+        let code = unsafe {
+            bindings::vision_create_color_code(self.port, id(0), id(1), id(2), id(3), id(4))
+        };
+        if code == PROS_ERR as u16 {
+            Err(from_errno())
+        } else {
+            Ok(ColorCode(code))
+        }
+    }
+
+    /// Loads the signature with the given ID from the sensor.
+    pub fn get_signature(&self, sig_id: u8) -> Result<VisionSignature, Error> {
+        VisionSignature::from_vision_signature_s_t(unsafe {
+            bindings::vision_get_signature(self.port, sig_id)
+        })
+    }
+
+    /// Saves a signature to the sensor with the given ID.
+    pub fn set_signature(&self, sig_id: u8, signature: &VisionSignature) -> Result<(), Error> {
+        let mut sig = signature.to_vision_signature_s_t();
+        check_pros_err(unsafe { bindings::vision_set_signature(self.port, sig_id, &mut sig) })?;
+        Ok(())
+    }
+
+    /// Gets the exposure setting of the sensor, on the range 0 to 150.
+    pub fn get_exposure(&self) -> Result<i32, Error> {
+        check_pros_err(unsafe { bindings::vision_get_exposure(self.port) })
+    }
+
+    /// Sets the exposure setting of the sensor, on the range 0 to 150.
+    pub fn set_exposure(&self, exposure: u8) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::vision_set_exposure(self.port, exposure) })?;
+        Ok(())
+    }
+
+    /// Gets the white balance of the sensor as an RGB color.
+    pub fn get_white_balance(&self) -> Result<u32, Error> {
+        Ok(check_pros_err(unsafe { bindings::vision_get_white_balance(self.port) })? as u32)
+    }
+
+    /// Sets the white balance of the sensor as an RGB color, disabling
+    /// automatic white balance.
+    pub fn set_white_balance(&self, rgb: u32) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::vision_set_white_balance(self.port, rgb as i32) })?;
+        Ok(())
+    }
+
+    /// Enables or disables automatic white balance.
+    pub fn set_auto_white_balance(&self, enable: bool) -> Result<(), Error> {
+        check_pros_err(unsafe {
+            bindings::vision_set_auto_white_balance(self.port, enable as u8)
+        })?;
+        Ok(())
+    }
+
+    /// Sets the color of the sensor's LED as an RGB color, overriding its
+    /// default behaviour of showing the most prominent detected object.
+    pub fn set_led(&self, rgb: u32) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::vision_set_led(self.port, rgb as i32) })?;
+        Ok(())
+    }
+
+    /// Restores the default behaviour of the sensor's LED.
+    pub fn clear_led(&self) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::vision_clear_led(self.port) })?;
+        Ok(())
+    }
+
+    /// Sets the origin of the coordinates reported for detected objects.
+    pub fn set_zero_point(&self, zero_point: VisionZeroPoint) -> Result<(), Error> {
+        let zero_point = match zero_point {
+            VisionZeroPoint::TopLeft => bindings::vision_zero_E_VISION_ZERO_TOPLEFT,
+            VisionZeroPoint::Center => bindings::vision_zero_E_VISION_ZERO_CENTER,
+        };
+        check_pros_err(unsafe { bindings::vision_set_zero_point(self.port, zero_point) })?;
+        Ok(())
+    }
+
+    /// Enables or disables the sensor's Wi-Fi mode.
+    pub fn set_wifi_mode(&self, enable: bool) -> Result<(), Error> {
+        check_pros_err(unsafe { bindings::vision_set_wifi_mode(self.port, enable as u8) })?;
+        Ok(())
+    }
+}
+
+fn read_objects(
+    count: u32,
+    read: impl FnOnce(u32, *mut bindings::vision_object_s_t) -> i32,
+) -> Result<Vec<VisionObject>, Error> {
+    let count = count.min(VisionSensor::MAX_OBJECTS);
+    let mut arr: Vec<bindings::vision_object_s_t> = Vec::with_capacity(count as usize);
+    let n = read(count, arr.as_mut_ptr());
+    if n == PROS_ERR {
+        // PROS reports that there are no objects to read with EDOM.
+        return if get_errno() == libc::EDOM {
+            Ok(Vec::new())
+        } else {
+            Err(from_errno())
+        };
+    }
+    // PROS returns how many objects it read, which is at most `count`; clamp
+    // it anyway so that an unexpected value cannot expose uninitialized memory.
+    unsafe { arr.set_len((n.max(0) as u32).min(count) as usize) };
+    // PROS fills entries for which there is no object with an error signature.
+    arr.into_iter()
+        .filter(|obj| obj.signature as u32 != bindings::VISION_OBJECT_ERR_SIG)
+        .map(VisionObject::from_vision_object_s_t)
+        .collect()
+}